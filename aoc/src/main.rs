@@ -0,0 +1,105 @@
+use common::runner::Runner;
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage: aoc <dayNN> [day options] | aoc --list";
+
+// every day this runner knows about, whether or not it was compiled in
+const DAYS: &[&str] = &[
+    "day01",
+    "day02",
+    "day03",
+    "day04",
+    "day05",
+    "day06",
+    "day07",
+    "day08",
+    "day09",
+    "day10",
+    "day11",
+    "day12",
+    "day13",
+    "day14",
+    "day15",
+    "day16",
+    "day17",
+    "day18",
+    "day19",
+];
+
+fn solver(day: &str) -> Option<fn(&Runner)> {
+    match day {
+        #[cfg(feature = "day01")]
+        "day01" => Some(day01::run),
+        #[cfg(feature = "day02")]
+        "day02" => Some(day02::run),
+        #[cfg(feature = "day03")]
+        "day03" => Some(day03::run),
+        #[cfg(feature = "day04")]
+        "day04" => Some(day04::run),
+        #[cfg(feature = "day05")]
+        "day05" => Some(day05::run),
+        #[cfg(feature = "day06")]
+        "day06" => Some(day06::run),
+        #[cfg(feature = "day07")]
+        "day07" => Some(day07::run),
+        #[cfg(feature = "day08")]
+        "day08" => Some(day08::run),
+        #[cfg(feature = "day09")]
+        "day09" => Some(day09::run),
+        #[cfg(feature = "day10")]
+        "day10" => Some(day10::run),
+        #[cfg(feature = "day11")]
+        "day11" => Some(day11::run),
+        #[cfg(feature = "day12")]
+        "day12" => Some(day12::run),
+        #[cfg(feature = "day13")]
+        "day13" => Some(day13::run),
+        #[cfg(feature = "day14")]
+        "day14" => Some(day14::run),
+        #[cfg(feature = "day15")]
+        "day15" => Some(day15::run),
+        #[cfg(feature = "day16")]
+        "day16" => Some(day16::run),
+        #[cfg(feature = "day17")]
+        "day17" => Some(day17::run),
+        #[cfg(feature = "day18")]
+        "day18" => Some(day18::run),
+        #[cfg(feature = "day19")]
+        "day19" => Some(day19::run),
+        _ => None,
+    }
+}
+
+fn compiled_days() -> Vec<&'static str> {
+    DAYS.iter().copied().filter(|day| solver(day).is_some()).collect()
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let day = match args.next() {
+        Some(arg) if arg == "--list" => {
+            compiled_days().iter().for_each(|day| println!("{}", day));
+            return;
+        }
+        Some(arg) => arg,
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    match (DAYS.iter().find(|&&d| d == day), solver(&day)) {
+        (Some(&day), Some(run)) => run(&Runner::with_args(day, args)),
+        (Some(day), None) => {
+            eprintln!("{} isn't compiled into this runner; rebuild with `--features {}`", day, day);
+            eprintln!("compiled in: {}", compiled_days().join(", "));
+            process::exit(2);
+        }
+        (None, _) => {
+            eprintln!("Unknown day: {}\n{}", day, USAGE);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// with the embedded-inputs feature, every input/dayNN.txt is baked into the binary via include_str!
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBEDDED_INPUTS").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let input_dir = Path::new(&manifest_dir).join("../input").canonicalize()
+        .expect("Couldn't find the input directory to embed.");
+    println!("cargo:rerun-if-changed={}", input_dir.display());
+
+    let mut inputs: Vec<(String, PathBuf)> = fs::read_dir(&input_dir)
+        .expect("Error reading the input directory.")
+        .map(|entry| entry.expect("Error reading input directory entry.").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| {
+            let day = path.file_stem()?.to_str()?.to_string();
+
+            // skips reproducers like day19.min.txt written by --minimize
+            match day.starts_with("day") && !day.contains('.') {
+                true => Some((day, path)),
+                false => None,
+            }
+        })
+        .collect();
+    inputs.sort();
+
+    let mut table = String::from("static EMBEDDED_INPUTS: &[(&str, &str)] = &[\n");
+    for (day, path) in inputs {
+        table.push_str(&format!("    ({:?}, include_str!({:?})),\n", day, path.display().to_string()));
+    }
+    table.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("embedded_inputs.rs"), table)
+        .expect("Error writing the embedded input table.");
+}
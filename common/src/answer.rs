@@ -0,0 +1,166 @@
+use crate::arith::Overflow;
+use crate::graph::CycleError;
+use num_bigint::BigInt;
+use serde_json::{json, Value};
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    NoSolution(String),
+}
+
+impl Answer {
+    pub fn none<S: Into<String>>(reason: S) -> Self {
+        Answer::NoSolution(reason.into())
+    }
+
+    pub fn is_solution(&self) -> bool {
+        !matches!(self, Answer::NoSolution(_))
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Answer::Integer(n) => Some(*n),
+            Answer::BigInteger(n) => i64::try_from(n).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Integer(n) => json!({ "answer": n }),
+            Answer::BigInteger(n) => json!({ "answer": n.to_string() }),
+            Answer::Text(s) => json!({ "answer": s }),
+            Answer::NoSolution(reason) => json!({ "answer": null, "no_solution": reason }),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::NoSolution(reason) => write!(f, "no solution ({})", reason),
+        }
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_wide_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Integer(n),
+                        Err(_) => Answer::BigInteger(BigInt::from(n)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_wide_int!(u64, usize, i128, u128);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i64::try_from(&n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or_else(|| Answer::none("no solution found"), Into::into)
+    }
+}
+
+// lets solvers `?` their way out with the reason a computation gave up
+impl From<Overflow> for Answer {
+    fn from(e: Overflow) -> Self {
+        Answer::none(e.to_string())
+    }
+}
+
+impl From<CycleError> for Answer {
+    fn from(e: CycleError) -> Self {
+        Answer::none(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Answer::from(42u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-7i32), Answer::Integer(-7));
+        assert_eq!(Answer::from(12usize), Answer::Integer(12));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(BigInt::from(u64::MAX)));
+        assert_eq!(Answer::from(BigInt::from(5)), Answer::Integer(5));
+    }
+
+    #[test]
+    fn test_from_option() {
+        assert_eq!(Answer::from(Some(3i64)), Answer::Integer(3));
+        assert_eq!(Answer::from(None::<i64>), Answer::none("no solution found"));
+        assert!(!Answer::from(None::<i64>).is_solution());
+        assert_eq!(Answer::from(Overflow("mul")), Answer::none("arithmetic overflow in mul"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(514579).to_string(), "514579");
+        assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::none("no pair sums to 2021").to_string(), "no solution (no pair sums to 2021)");
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(Answer::from(5).to_json(), json!({ "answer": 5 }));
+        assert_eq!(Answer::from(u64::MAX).to_json(), json!({ "answer": "18446744073709551615" }));
+        assert_eq!(Answer::none("reason").to_json(), json!({ "answer": null, "no_solution": "reason" }));
+    }
+
+    #[test]
+    fn test_as_i64() {
+        assert_eq!(Answer::from(5u8).as_i64(), Some(5));
+        assert_eq!(Answer::from(u64::MAX).as_i64(), None);
+        assert_eq!(Answer::from("5").as_i64(), None);
+    }
+}
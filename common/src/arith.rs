@@ -0,0 +1,75 @@
+use std::fmt;
+
+// by default overflow wraps in release and panics in debug, like any i64;
+// `checked` turns it into an Overflow error, and `bigint` makes it impossible
+#[cfg(feature = "bigint")]
+pub type Num = num_bigint::BigInt;
+
+#[cfg(not(feature = "bigint"))]
+pub type Num = i64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Overflow(pub &'static str);
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arithmetic overflow in {}", self.0)
+    }
+}
+
+pub fn num(n: i64) -> Num {
+    Num::from(n)
+}
+
+macro_rules! checked_op {
+    ($name:ident, $checked:ident, $op:tt) => {
+        #[cfg(all(feature = "checked", not(feature = "bigint")))]
+        pub fn $name(a: Num, b: Num) -> Result<Num, Overflow> {
+            a.$checked(b).ok_or(Overflow(stringify!($name)))
+        }
+
+        #[cfg(not(all(feature = "checked", not(feature = "bigint"))))]
+        pub fn $name(a: Num, b: Num) -> Result<Num, Overflow> {
+            Ok(a $op b)
+        }
+    };
+}
+
+checked_op!(add, checked_add, +);
+checked_op!(sub, checked_sub, -);
+checked_op!(mul, checked_mul, *);
+
+pub fn sum<I: IntoIterator<Item = Num>>(nums: I) -> Result<Num, Overflow> {
+    nums.into_iter().try_fold(num(0), add)
+}
+
+pub fn product<I: IntoIterator<Item = Num>>(nums: I) -> Result<Num, Overflow> {
+    nums.into_iter().try_fold(num(1), mul)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arith() {
+        assert_eq!(add(num(2), num(3)), Ok(num(5)));
+        assert_eq!(sub(num(2), num(3)), Ok(num(-1)));
+        assert_eq!(sum(vec![num(1), num(2), num(3)]), Ok(num(6)));
+        assert_eq!(product(vec![num(4), num(5), num(6)]), Ok(num(120)));
+        assert_eq!(Overflow("mul").to_string(), "arithmetic overflow in mul");
+    }
+
+    #[cfg(all(feature = "checked", not(feature = "bigint")))]
+    #[test]
+    fn test_checked() {
+        assert_eq!(add(num(i64::MAX), num(1)), Err(Overflow("add")));
+        assert_eq!(product(vec![num(1 << 40), num(1 << 40)]), Err(Overflow("mul")));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint() {
+        assert_eq!(product(vec![num(1 << 40), num(1 << 40)]).map(|n| n.to_string()), Ok("1208925819614629174706176".to_string()));
+    }
+}
@@ -0,0 +1,231 @@
+use std::iter::FromIterator;
+
+const WORD_BITS: usize = 64;
+
+// everything that only needs the words is shared between the fixed and growable sets
+macro_rules! impl_set_ops {
+    ($t:ty, $($generics:tt)*) => {
+        impl<$($generics)*> $t {
+            pub fn contains(&self, i: usize) -> bool {
+                self.words.get(i / WORD_BITS).is_some_and(|w| w & (1 << (i % WORD_BITS)) != 0)
+            }
+
+            pub fn remove(&mut self, i: usize) -> bool {
+                let present = self.contains(i);
+                if present {
+                    self.words[i / WORD_BITS] &= !(1 << (i % WORD_BITS));
+                }
+
+                present
+            }
+
+            pub fn len(&self) -> usize {
+                self.words.iter().map(|w| w.count_ones() as usize).sum()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.words.iter().all(|&w| w == 0)
+            }
+
+            pub fn clear(&mut self) {
+                self.words.iter_mut().for_each(|w| *w = 0);
+            }
+
+            pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+                self.words.iter()
+                    .enumerate()
+                    .flat_map(|(i, &word)| {
+                        let mut word = word;
+
+                        std::iter::from_fn(move || match word {
+                            0 => None,
+                            _ => {
+                                let bit = word.trailing_zeros() as usize;
+                                word &= word - 1;
+
+                                Some(i * WORD_BITS + bit)
+                            }
+                        })
+                    })
+            }
+
+            pub fn is_subset(&self, other: &Self) -> bool {
+                self.words.iter()
+                    .enumerate()
+                    .all(|(i, &w)| w & !other.words.get(i).copied().unwrap_or(0) == 0)
+            }
+
+            pub fn intersect_with(&mut self, other: &Self) {
+                for (i, w) in self.words.iter_mut().enumerate() {
+                    *w &= other.words.get(i).copied().unwrap_or(0);
+                }
+            }
+
+            pub fn difference_with(&mut self, other: &Self) {
+                for (w, &o) in self.words.iter_mut().zip(other.words.iter()) {
+                    *w &= !o;
+                }
+            }
+
+            pub fn intersection(&self, other: &Self) -> Self {
+                let mut result = self.clone();
+                result.intersect_with(other);
+
+                result
+            }
+
+            pub fn union(&self, other: &Self) -> Self {
+                let mut result = self.clone();
+                result.union_with(other);
+
+                result
+            }
+
+            pub fn difference(&self, other: &Self) -> Self {
+                let mut result = self.clone();
+                result.difference_with(other);
+
+                result
+            }
+        }
+    };
+}
+
+// WORDS * 64 bits on the stack; inserting past the end panics
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedBitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> FixedBitSet<WORDS> {
+    pub const CAPACITY: usize = WORDS * WORD_BITS;
+
+    pub fn new() -> Self {
+        FixedBitSet { words: [0; WORDS] }
+    }
+
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < Self::CAPACITY, "{} out of range for a {} bit set", i, Self::CAPACITY);
+        let absent = !self.contains(i);
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+
+        absent
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        for (w, &o) in self.words.iter_mut().zip(other.words.iter()) {
+            *w |= o;
+        }
+    }
+}
+
+impl_set_ops!(FixedBitSet<WORDS>, const WORDS: usize);
+
+impl<const WORDS: usize> Default for FixedBitSet<WORDS> {
+    fn default() -> Self {
+        FixedBitSet::new()
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for FixedBitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = FixedBitSet::new();
+        iter.into_iter().for_each(|i| { set.insert(i); });
+
+        set
+    }
+}
+
+// grows to fit the largest element inserted
+#[derive(Clone, Debug, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet { words: Vec::new() }
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        BitSet { words: vec![0; bits.div_ceil(WORD_BITS)] }
+    }
+
+    pub fn insert(&mut self, i: usize) -> bool {
+        if i / WORD_BITS >= self.words.len() {
+            self.words.resize(i / WORD_BITS + 1, 0);
+        }
+        let absent = !self.contains(i);
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+
+        absent
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (w, &o) in self.words.iter_mut().zip(other.words.iter()) {
+            *w |= o;
+        }
+    }
+}
+
+impl_set_ops!(BitSet,);
+
+// trailing empty words don't change which elements are in the set
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_subset(other) && other.is_subset(self)
+    }
+}
+
+impl Eq for BitSet {}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        iter.into_iter().for_each(|i| { set.insert(i); });
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_bit_set() {
+        let mut a: FixedBitSet<1> = "abcx".bytes().map(|b| (b - b'a') as usize).collect();
+        let b: FixedBitSet<1> = "abc".bytes().map(|b| (b - b'a') as usize).collect();
+
+        assert_eq!(a.len(), 4);
+        assert!(!a.insert(0));
+        assert!(a.insert(63));
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![0, 1, 2, 23, 63]);
+        assert_eq!(a.intersection(&b), b);
+        assert!(b.is_subset(&a) && !a.is_subset(&b));
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), vec![23, 63]);
+        assert!(a.remove(63) && !a.remove(63));
+        assert_eq!(FixedBitSet::<2>::CAPACITY, 128);
+    }
+
+    #[test]
+    fn test_bit_set() {
+        let mut a: BitSet = vec![1, 200].into_iter().collect();
+        let b: BitSet = vec![1, 2, 3].into_iter().collect();
+
+        assert!(a.contains(200) && !a.contains(199) && !a.contains(10_000));
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![1, 2, 3, 200]);
+        assert_eq!(b.union(&a).len(), 4);
+        assert_eq!(a.intersection(&b), vec![1].into_iter().collect());
+        assert_eq!(b.intersection(&a), vec![1].into_iter().collect());
+
+        a.remove(200);
+        assert_eq!(a, vec![1].into_iter().collect());
+        a.clear();
+        assert!(a.is_empty());
+        assert_eq!(BitSet::with_capacity(65), BitSet::new());
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// checking the clock on every step is measurable in tight loops like day15's, which tick_every this often
+pub const CLOCK_CHECK_INTERVAL: u64 = 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Exhausted {
+    Deadline(Duration),
+    Steps(u64),
+    Cancelled,
+}
+
+impl fmt::Display for Exhausted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Exhausted::Deadline(timeout) => write!(f, "deadline of {:?} exceeded", timeout),
+            Exhausted::Steps(max_steps) => write!(f, "step limit of {} exceeded", max_steps),
+            Exhausted::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Budget {
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+    max_steps: Option<u64>,
+    steps: Arc<AtomicU64>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Budget {
+    pub fn new(timeout: Option<Duration>, max_steps: Option<u64>) -> Self {
+        Budget {
+            timeout,
+            deadline: timeout.map(|t| Instant::now() + t),
+            max_steps,
+            steps: Arc::new(AtomicU64::new(0)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Budget::new(None, None)
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // checks the clock on every step, so a solver with slow steps stops as soon as its deadline passes
+    pub fn tick(&self) -> Result<(), Exhausted> {
+        self.tick_every(1)
+    }
+
+    // for hot loops, only looks at the clock once every interval steps
+    pub fn tick_every(&self, interval: u64) -> Result<(), Exhausted> {
+        let steps = self.steps.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if let Some(max_steps) = self.max_steps {
+            if steps > max_steps {
+                return Err(Exhausted::Steps(max_steps));
+            }
+        }
+
+        if steps.is_multiple_of(interval) {
+            self.check()
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check(&self) -> Result<(), Exhausted> {
+        if self.is_cancelled() {
+            return Err(Exhausted::Cancelled);
+        }
+
+        match (self.timeout, self.deadline) {
+            (Some(timeout), Some(deadline)) if Instant::now() >= deadline => Err(Exhausted::Deadline(timeout)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited() {
+        let budget = Budget::unlimited();
+
+        for _ in 0..10_000 {
+            assert_eq!(budget.tick(), Ok(()));
+        }
+        assert_eq!(budget.steps(), 10_000);
+    }
+
+    #[test]
+    fn test_step_limit() {
+        let budget = Budget::new(None, Some(3));
+
+        assert_eq!(budget.tick(), Ok(()));
+        assert_eq!(budget.tick(), Ok(()));
+        assert_eq!(budget.tick(), Ok(()));
+        assert_eq!(budget.tick(), Err(Exhausted::Steps(3)));
+    }
+
+    #[test]
+    fn test_deadline() {
+        let budget = Budget::new(Some(Duration::from_millis(0)), None);
+
+        assert_eq!(budget.check(), Err(Exhausted::Deadline(Duration::from_millis(0))));
+        assert_eq!(budget.tick(), Err(Exhausted::Deadline(Duration::from_millis(0))));
+        for _ in 2..CLOCK_CHECK_INTERVAL {
+            assert_eq!(budget.tick_every(CLOCK_CHECK_INTERVAL), Ok(()));
+        }
+        assert_eq!(budget.tick_every(CLOCK_CHECK_INTERVAL), Err(Exhausted::Deadline(Duration::from_millis(0))));
+    }
+
+    #[test]
+    fn test_deadline_with_slow_steps() {
+        let timeout = Duration::from_millis(50);
+        let budget = Budget::new(Some(timeout), None);
+        let start = Instant::now();
+
+        let result = (0..1000).try_for_each(|_| {
+            std::thread::sleep(Duration::from_millis(10));
+            budget.tick()
+        });
+
+        assert_eq!(result, Err(Exhausted::Deadline(timeout)));
+        assert!(budget.steps() < 10);
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_cancel_is_shared_between_clones() {
+        let budget = Budget::unlimited();
+        let token = budget.clone();
+
+        token.cancel();
+
+        assert!(budget.is_cancelled());
+        assert_eq!(budget.check(), Err(Exhausted::Cancelled));
+    }
+}
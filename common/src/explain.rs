@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static FACTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// records a human-readable fact about how an answer was reached; arguments are only formatted in explain mode
+#[macro_export]
+macro_rules! explain {
+    ($($arg:tt)*) => {
+        if $crate::explain::is_enabled() {
+            $crate::explain::record(format!($($arg)*));
+        }
+    };
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn record(fact: String) {
+    FACTS.lock().unwrap().push(fact);
+}
+
+pub fn take() -> Vec<String> {
+    std::mem::take(&mut *FACTS.lock().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain() {
+        explain!("ignored {}", 1);
+        assert!(take().is_empty());
+
+        enable();
+        explain!("entries {} and {} sum to {}", 1721, 299, 2020);
+        explain!("second fact");
+
+        assert_eq!(take(), vec!["entries 1721 and 299 sum to 2020", "second fact"]);
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+use std::array;
+use std::ops::{Add, AddAssign, Index, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const D: usize>(pub [i64; D]);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const D: usize>(pub [i64; D]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+pub type Vector2 = Vector<2>;
+
+impl<const D: usize> Point<D> {
+    pub fn origin() -> Self {
+        Point([0; D])
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (*self - *other).manhattan()
+    }
+
+    // the 3^D - 1 points touching this one, diagonals included
+    pub fn neighbours(&self) -> impl Iterator<Item = Point<D>> {
+        let p = *self;
+
+        Vector::directions().map(move |d| p + d)
+    }
+}
+
+impl<const D: usize> Vector<D> {
+    pub fn zero() -> Self {
+        Vector([0; D])
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    // every offset with components in -1..=1 except zero, without allocating
+    pub fn directions() -> impl Iterator<Item = Vector<D>> {
+        let count = 3usize.pow(D as u32);
+
+        (0..count)
+            .filter(move |&k| k != count / 2)
+            .map(|mut k| {
+                Vector(array::from_fn(|_| {
+                    let c = (k % 3) as i64 - 1;
+                    k /= 3;
+
+                    c
+                }))
+            })
+    }
+
+    // one unit step each way along every axis
+    pub fn units() -> impl Iterator<Item = Vector<D>> {
+        (0..D).flat_map(|axis| {
+            [1, -1].iter().map(move |&sign| Vector(array::from_fn(|i| if i == axis { sign } else { 0 })))
+        })
+    }
+
+    // quarter turns in the plane of two axes, turning `from` towards `to`; negative turns go the other way
+    pub fn rotate(&self, from: usize, to: usize, quarter_turns: i64) -> Self {
+        let mut v = *self;
+
+        for _ in 0..quarter_turns.rem_euclid(4) {
+            let (a, b) = (v.0[from], v.0[to]);
+            v.0[from] = -b;
+            v.0[to] = a;
+        }
+
+        v
+    }
+}
+
+// x points east and y points north
+impl Vector<2> {
+    pub const NORTH: Vector<2> = Vector([0, 1]);
+    pub const EAST: Vector<2> = Vector([1, 0]);
+    pub const SOUTH: Vector<2> = Vector([0, -1]);
+    pub const WEST: Vector<2> = Vector([-1, 0]);
+
+    pub fn turn_left(&self, quarter_turns: i64) -> Self {
+        self.rotate(0, 1, quarter_turns)
+    }
+
+    pub fn turn_right(&self, quarter_turns: i64) -> Self {
+        self.rotate(0, 1, -quarter_turns)
+    }
+}
+
+impl<const D: usize> Index<usize> for Point<D> {
+    type Output = i64;
+
+    fn index(&self, i: usize) -> &i64 {
+        &self.0[i]
+    }
+}
+
+impl<const D: usize> Index<usize> for Vector<D> {
+    type Output = i64;
+
+    fn index(&self, i: usize) -> &i64 {
+        &self.0[i]
+    }
+}
+
+impl<const D: usize> Add<Vector<D>> for Point<D> {
+    type Output = Point<D>;
+
+    fn add(self, rhs: Vector<D>) -> Point<D> {
+        Point(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const D: usize> AddAssign<Vector<D>> for Point<D> {
+    fn add_assign(&mut self, rhs: Vector<D>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: usize> Sub<Vector<D>> for Point<D> {
+    type Output = Point<D>;
+
+    fn sub(self, rhs: Vector<D>) -> Point<D> {
+        self + -rhs
+    }
+}
+
+impl<const D: usize> SubAssign<Vector<D>> for Point<D> {
+    fn sub_assign(&mut self, rhs: Vector<D>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: usize> Sub for Point<D> {
+    type Output = Vector<D>;
+
+    fn sub(self, rhs: Point<D>) -> Vector<D> {
+        Vector(array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const D: usize> Add for Vector<D> {
+    type Output = Vector<D>;
+
+    fn add(self, rhs: Vector<D>) -> Vector<D> {
+        Vector(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const D: usize> AddAssign for Vector<D> {
+    fn add_assign(&mut self, rhs: Vector<D>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: usize> Sub for Vector<D> {
+    type Output = Vector<D>;
+
+    fn sub(self, rhs: Vector<D>) -> Vector<D> {
+        self + -rhs
+    }
+}
+
+impl<const D: usize> SubAssign for Vector<D> {
+    fn sub_assign(&mut self, rhs: Vector<D>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: usize> Neg for Vector<D> {
+    type Output = Vector<D>;
+
+    fn neg(self) -> Vector<D> {
+        Vector(self.0.map(|c| -c))
+    }
+}
+
+impl<const D: usize> Mul<i64> for Vector<D> {
+    type Output = Vector<D>;
+
+    fn mul(self, rhs: i64) -> Vector<D> {
+        Vector(self.0.map(|c| c * rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point([1, 2]);
+        p += Vector::EAST * 10;
+        p -= Vector::NORTH * 4;
+
+        assert_eq!(p, Point([11, -2]));
+        assert_eq!(p - Point([1, 2]), Vector([10, -4]));
+        assert_eq!(p.manhattan_distance(&Point::origin()), 13);
+        assert_eq!(p[0], 11);
+        assert_eq!(Vector::EAST.turn_left(1), Vector::NORTH);
+        assert_eq!(Vector::EAST.turn_right(1), Vector::SOUTH);
+        assert_eq!(Vector([10, 4]).turn_right(1), Vector([4, -10]));
+        assert_eq!(Vector([10, 4]).turn_left(3), Vector([4, -10]));
+        assert_eq!(Vector([10, 4]).turn_left(-1), Vector([4, -10]));
+        assert_eq!(Vector([1, 2, 3]).rotate(1, 2, 2), Vector([1, -2, -3]));
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        assert_eq!(Vector::<2>::directions().count(), 8);
+        assert_eq!(Vector::<3>::directions().count(), 26);
+        assert_eq!(Vector::<4>::directions().count(), 80);
+        assert!(Vector::<3>::directions().all(|d| d != Vector::zero() && d.0.iter().all(|c| c.abs() <= 1)));
+        assert_eq!(Point([0, 0]).neighbours().filter(|n| n.manhattan_distance(&Point::origin()) == 1).count(), 4);
+        assert_eq!(
+            Vector::<2>::units().collect::<Vec<_>>(),
+            vec![Vector::EAST, Vector::WEST, Vector::NORTH, Vector::SOUTH]
+        );
+    }
+}
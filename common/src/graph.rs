@@ -0,0 +1,239 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CycleError;
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "graph contains a cycle")
+    }
+}
+
+// nodes are interned into indices, so the algorithms only ever touch Vecs
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, i64)>>,
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph { nodes: Vec::new(), index: HashMap::new(), edges: Vec::new() }
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+
+        self.nodes.push(node.clone());
+        self.edges.push(Vec::new());
+        self.index.insert(node, self.nodes.len() - 1);
+
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, weight: i64) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn edges<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, i64)> + 'a {
+        let edges = self.index.get(node).map_or(&[][..], |&i| &self.edges[i][..]);
+
+        edges.iter().map(move |&(to, weight)| (&self.nodes[to], weight))
+    }
+
+    pub fn reversed(&self) -> Self {
+        let mut reversed = Graph { nodes: self.nodes.clone(), index: self.index.clone(), edges: vec![Vec::new(); self.len()] };
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                reversed.edges[to].push((from, weight));
+            }
+        }
+
+        reversed
+    }
+
+    // nodes reachable from start, start included, nearest first
+    pub fn bfs(&self, start: &N) -> Vec<&N> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut queue: VecDeque<usize> = self.index.get(start).copied().into_iter().collect();
+
+        queue.iter().for_each(|&i| seen[i] = true);
+        while let Some(i) = queue.pop_front() {
+            order.push(&self.nodes[i]);
+
+            for &(to, _) in &self.edges[i] {
+                if !seen[to] {
+                    seen[to] = true;
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        order
+    }
+
+    // nodes reachable from start in preorder, following edges in insertion order
+    pub fn dfs(&self, start: &N) -> Vec<&N> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack: Vec<usize> = self.index.get(start).copied().into_iter().collect();
+
+        while let Some(i) = stack.pop() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            order.push(&self.nodes[i]);
+
+            stack.extend(self.edges[i].iter().rev().map(|&(to, _)| to).filter(|&to| !seen[to]));
+        }
+
+        order
+    }
+
+    pub fn topological_sort(&self) -> Result<Vec<&N>, CycleError> {
+        self.topological_order().map(|order| order.into_iter().map(|i| &self.nodes[i]).collect())
+    }
+
+    // Kahn's algorithm; anything left with incoming edges sits on or behind a cycle
+    fn topological_order(&self) -> Result<Vec<usize>, CycleError> {
+        let mut in_degree = vec![0; self.len()];
+        self.edges.iter().flatten().for_each(|&(to, _)| in_degree[to] += 1);
+
+        let mut queue: VecDeque<usize> = (0..self.len()).filter(|&i| in_degree[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+
+            for &(to, _) in &self.edges[i] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        match order.len() == self.len() {
+            true => Ok(order),
+            false => Err(CycleError),
+        }
+    }
+
+    // number of distinct paths from one node to another, each node's count computed once
+    pub fn count_paths(&self, from: &N, to: &N) -> Result<u64, CycleError> {
+        self.count_paths_with(from, to, 0, 1, |a, b| Ok(a + b))
+    }
+
+    // count_paths for any counter type, e.g. one that reports overflow instead of wrapping
+    pub fn count_paths_with<T: Clone, E: From<CycleError>>(
+        &self,
+        from: &N,
+        to: &N,
+        zero: T,
+        one: T,
+        add: impl Fn(T, T) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let (from, to) = match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => return Ok(zero),
+        };
+
+        let mut paths = vec![zero.clone(); self.len()];
+        for i in self.topological_order()?.into_iter().rev() {
+            paths[i] = match i == to {
+                true => one.clone(),
+                false => self.edges[i].iter().try_fold(zero.clone(), |acc, &(next, _)| add(acc, paths[next].clone()))?,
+            };
+        }
+
+        Ok(paths.swap_remove(from))
+    }
+
+    // each node is worth 1 plus the weighted worth of everything it points to
+    pub fn weighted_sum(&self, start: &N) -> Result<i64, CycleError> {
+        let start = match self.index.get(start) {
+            Some(&start) => start,
+            None => return Ok(0),
+        };
+
+        let mut sums = vec![0; self.len()];
+        for i in self.topological_order()?.into_iter().rev() {
+            sums[i] = 1 + self.edges[i].iter().map(|&(next, weight)| weight * sums[next]).sum::<i64>();
+        }
+
+        Ok(sums[start])
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 2);
+        graph.add_edge("a", "c", 3);
+        graph.add_edge("b", "d", 4);
+        graph.add_edge("c", "d", 5);
+
+        graph
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = diamond();
+
+        assert_eq!(graph.bfs(&"a"), vec![&"a", &"b", &"c", &"d"]);
+        assert_eq!(graph.dfs(&"a"), vec![&"a", &"b", &"d", &"c"]);
+        assert_eq!(graph.reversed().bfs(&"d"), vec![&"d", &"b", &"c", &"a"]);
+        assert_eq!(graph.edges(&"a").collect::<Vec<_>>(), vec![(&"b", 2), (&"c", 3)]);
+        assert!(graph.bfs(&"z").is_empty());
+    }
+
+    #[test]
+    fn test_dag_algorithms() {
+        let mut graph = diamond();
+
+        assert_eq!(graph.topological_sort(), Ok(vec![&"a", &"b", &"c", &"d"]));
+        assert_eq!(graph.count_paths(&"a", &"d"), Ok(2));
+        assert_eq!(graph.count_paths(&"d", &"a"), Ok(0));
+        assert_eq!(graph.weighted_sum(&"a"), Ok(1 + 2 * 5 + 3 * 6));
+
+        graph.add_edge("d", "a", 1);
+        assert_eq!(graph.topological_sort(), Err(CycleError));
+        assert_eq!(graph.count_paths(&"a", &"d"), Err(CycleError));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::files;
+use std::path::Path;
+use tracing::debug;
+
+#[cfg(feature = "embedded-inputs")]
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+#[cfg(not(feature = "embedded-inputs"))]
+static EMBEDDED_INPUTS: &[(&str, &str)] = &[];
+
+pub fn input_path(day: &str) -> String {
+    format!("input/{}.txt", day)
+}
+
+pub fn embedded_input(day: &str) -> Option<&'static str> {
+    EMBEDDED_INPUTS.iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, input)| input)
+}
+
+pub fn embedded_days() -> Vec<&'static str> {
+    EMBEDDED_INPUTS.iter().map(|&(day, _)| day).collect()
+}
+
+// the file on disk wins, so an edited input is picked up without rebuilding
+pub fn get_input_as_string(day: &str) -> String {
+    let path = input_path(day);
+
+    if Path::new(&path).is_file() {
+        return files::get_file_as_string(&path);
+    }
+
+    debug!(day, "using embedded input");
+    embedded_input(day)
+        .unwrap_or_else(|| panic!("Couldn't find input file: {} (and no embedded copy was built in)", path))
+        .to_string()
+}
+
+pub fn get_input_lines(day: &str) -> Vec<String> {
+    let path = input_path(day);
+
+    if Path::new(&path).is_file() {
+        return files::get_file_lines(&path);
+    }
+
+    debug!(day, "using embedded input");
+    embedded_input(day)
+        .unwrap_or_else(|| panic!("Couldn't find input file: {} (and no embedded copy was built in)", path))
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(input_path("day08"), "input/day08.txt");
+    }
+
+    #[cfg(not(feature = "embedded-inputs"))]
+    #[test]
+    fn test_no_embedded_inputs() {
+        assert_eq!(embedded_input("day01"), None);
+        assert!(embedded_days().is_empty());
+    }
+
+    #[cfg(feature = "embedded-inputs")]
+    #[test]
+    fn test_embedded_inputs() {
+        assert!(embedded_days().contains(&"day01"));
+        assert_eq!(embedded_input("day01"), Some(include_str!("../../input/day01.txt")));
+        assert_eq!(get_input_lines("day01"), include_str!("../../input/day01.txt").lines().collect::<Vec<_>>());
+    }
+}
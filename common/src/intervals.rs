@@ -0,0 +1,206 @@
+use serde::{Serialize, Serializer};
+use std::iter::FromIterator;
+use std::ops::RangeInclusive;
+
+pub trait Discrete: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn succ(self) -> Option<Self>;
+    fn pred(self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// kept sorted, with overlapping and touching ranges merged, so lookups can binary search
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+
+        self.ranges = normalize(ranges);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+
+        i < self.ranges.len() && *self.ranges[i].start() <= value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        IntervalSet { ranges: normalize(self.ranges.iter().chain(other.ranges.iter()).cloned().collect()) }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            match a.end() < b.end() {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = Some(T::MIN);
+
+        for r in &self.ranges {
+            if let (Some(start), Some(end)) = (next, r.start().pred()) {
+                if start <= end {
+                    ranges.push(start..=end);
+                }
+            }
+            next = r.end().succ();
+        }
+        if let Some(start) = next {
+            ranges.push(start..=T::MAX);
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+fn normalize<T: Discrete>(mut ranges: Vec<RangeInclusive<T>>) -> Vec<RangeInclusive<T>> {
+    ranges.retain(|r| r.start() <= r.end());
+    ranges.sort_by_key(|r| *r.start());
+
+    let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(ranges.len());
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if last.end().succ().is_none_or(|after| *r.start() <= after) => {
+                *last = *last.start()..=*last.end().max(r.end());
+            }
+            _ => merged.push(r),
+        }
+    }
+
+    merged
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        IntervalSet { ranges: normalize(iter.into_iter().collect()) }
+    }
+}
+
+impl<T: Serialize> Serialize for IntervalSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.ranges)
+    }
+}
+
+// splits the number line at every range boundary, so each segment knows exactly which sets cover it
+#[derive(Clone, Debug)]
+pub struct IntervalIndex<T> {
+    starts: Vec<T>,
+    covering: Vec<Vec<usize>>,
+}
+
+impl<T: Discrete> IntervalIndex<T> {
+    pub fn new(sets: &[IntervalSet<T>]) -> Self {
+        let mut starts: Vec<T> = sets.iter()
+            .flat_map(|set| set.ranges())
+            .flat_map(|r| std::iter::once(*r.start()).chain(r.end().succ()))
+            .collect();
+        starts.sort();
+        starts.dedup();
+
+        let covering = starts.iter()
+            .map(|&start| (0..sets.len()).filter(|&i| sets[i].contains(start)).collect())
+            .collect();
+
+        IntervalIndex { starts, covering }
+    }
+
+    // indices of the sets containing value, in the order they were given
+    pub fn covering(&self, value: T) -> &[usize] {
+        match self.starts.partition_point(|&start| start <= value) {
+            0 => &[],
+            i => &self.covering[i - 1],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<u32> = vec![5..=7, 1..=3, 2..=4, 10..=12].into_iter().collect();
+        let b: IntervalSet<u32> = vec![3..=10].into_iter().collect();
+
+        assert_eq!(a.ranges().cloned().collect::<Vec<_>>(), vec![1..=7, 10..=12]);
+        assert!(a.contains(1) && a.contains(7) && a.contains(11));
+        assert!(!a.contains(0) && !a.contains(8) && !a.contains(13));
+        assert_eq!(a.union(&b).ranges().cloned().collect::<Vec<_>>(), vec![1..=12]);
+        assert_eq!(a.intersection(&b).ranges().cloned().collect::<Vec<_>>(), vec![3..=7, 10..=10]);
+        assert_eq!(a.complement().ranges().cloned().collect::<Vec<_>>(), vec![0..=0, 8..=9, 13..=u32::MAX]);
+        assert_eq!(a.complement().complement(), a);
+        assert!(IntervalSet::<u8>::new().complement().contains(255));
+    }
+
+    #[test]
+    fn test_index() {
+        let sets: Vec<IntervalSet<usize>> = vec![
+            vec![1..=3, 5..=7].into_iter().collect(),
+            vec![6..=11, 33..=44].into_iter().collect(),
+        ];
+        let index = IntervalIndex::new(&sets);
+
+        assert_eq!(index.covering(0), &[] as &[usize]);
+        assert_eq!(index.covering(2), &[0]);
+        assert_eq!(index.covering(4), &[] as &[usize]);
+        assert_eq!(index.covering(7), &[0, 1]);
+        assert_eq!(index.covering(40), &[1]);
+        assert_eq!(index.covering(45), &[] as &[usize]);
+    }
+}
@@ -3,34 +3,63 @@ macro_rules! vec_of_strings {
     ($($x:expr),*$(,)?) => (vec![$($x.to_string()),*]);
 }
 
+pub mod answer;
+pub mod arith;
+pub mod bitset;
+pub mod budget;
+pub mod explain;
+pub mod geom;
+pub mod graph;
+pub mod inputs;
+pub mod intervals;
+pub mod minimize;
+pub mod progress;
+pub mod runner;
 
 pub mod files {
     use std::fs::{File, read_to_string};
     use std::io::prelude::*;
     use std::io::BufReader;
+    use tracing::{debug, debug_span};
 
     pub fn get_file_bytes(file_name: &str) -> Vec<u8> {
+        let _span = debug_span!("read", file = file_name).entered();
+
         let mut file = File::open(file_name)
             .expect(format!("Couldn't find file with name: {}", file_name).as_str());
 
         let mut buf = Vec::new();
         file.read_to_end(&mut buf).expect("Error reading file bytes.");
 
+        debug!(bytes = buf.len(), "read file");
+
         buf
     }
 
     pub fn get_file_lines(file_name: &str) -> Vec<String> {
+        let _span = debug_span!("read", file = file_name).entered();
+
         let file = File::open(file_name)
             .expect(format!("Couldn't find file with name: {}", file_name).as_str());
 
-        BufReader::new(file)
+        let lines: Vec<String> = BufReader::new(file)
             .lines()
             .map(|l| l.expect("Error parsing line."))
-            .collect()
+            .collect();
+
+        debug!(lines = lines.len(), "read file");
+
+        lines
     }
 
     pub fn get_file_as_string(file_name: &str) -> String {
-        read_to_string(file_name)
-            .expect(format!("Couldn't read file with name: {} to a string", file_name).as_str())
+        let _span = debug_span!("read", file = file_name).entered();
+
+        let contents = read_to_string(file_name)
+            .expect(format!("Couldn't read file with name: {} to a string", file_name).as_str());
+
+        debug!(bytes = contents.len(), "read file");
+
+        contents
     }
 }
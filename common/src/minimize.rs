@@ -0,0 +1,133 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Failure {
+    Panic,
+    Timeout,
+    Mismatch,
+}
+
+impl FromStr for Failure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "panic" => Ok(Failure::Panic),
+            "timeout" => Ok(Failure::Timeout),
+            "mismatch" => Ok(Failure::Mismatch),
+            x => Err(format!("Invalid failure: {}", x)),
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Panic => write!(f, "panic"),
+            Failure::Timeout => write!(f, "timeout"),
+            Failure::Mismatch => write!(f, "mismatch"),
+        }
+    }
+}
+
+// Zeller's ddmin: drop ever smaller chunks, keeping whichever subset or complement still fails
+pub fn ddmin<T: Clone>(mut items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut n = 2;
+
+    while items.len() >= 2 {
+        let chunk_size = items.len().div_ceil(n);
+        let chunks: Vec<(usize, usize)> = (0..items.len()).step_by(chunk_size)
+            .map(|start| (start, (start + chunk_size).min(items.len())))
+            .collect();
+
+        if let Some(&(start, end)) = chunks.iter().find(|&&(start, end)| fails(&items[start..end])) {
+            items = items[start..end].to_vec();
+            n = 2;
+            continue;
+        }
+
+        let complement = |&(start, end): &(usize, usize)| [&items[..start], &items[end..]].concat();
+        if let Some(smaller) = chunks.iter().map(complement).find(|c| fails(c)) {
+            items = smaller;
+            n = (n - 1).max(2);
+            continue;
+        }
+
+        if n >= items.len() {
+            break;
+        }
+        n = (n * 2).min(items.len());
+    }
+
+    items
+}
+
+// blank-line separated records go first so whole sections can disappear in one go, then single lines
+pub fn minimize_lines(lines: Vec<String>, mut fails: impl FnMut(&[String]) -> bool) -> Vec<String> {
+    let records = ddmin(split_records(&lines), |records| fails(&join_records(records)));
+
+    ddmin(join_records(&records), fails)
+}
+
+pub fn split_records(lines: &[String]) -> Vec<Vec<String>> {
+    lines.split(|line| line.trim().is_empty())
+        .map(|record| record.to_vec())
+        .collect()
+}
+
+pub fn join_records(records: &[Vec<String>]) -> Vec<String> {
+    records.join(&String::new())
+}
+
+// the default hook would print every panic the minimiser provokes on purpose
+pub fn panics(f: impl FnOnce()) -> bool {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.is_err()
+}
+
+pub fn reproducer_path(day: &str) -> String {
+    format!("input/{}.min.txt", day)
+}
+
+pub fn write_reproducer(path: &str, lines: &[String]) -> io::Result<()> {
+    fs::write(path, lines.iter().map(|line| format!("{}\n", line)).collect::<String>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ddmin() {
+        let items: Vec<u32> = (1..=100).collect();
+
+        assert_eq!(ddmin(items.clone(), |items| items.contains(&37) && items.contains(&81)), vec![37, 81]);
+
+        // ddmin is only 1-minimal: no single element can go, though a smaller failing subset may exist
+        let large_sum = |items: &[u32]| items.iter().sum::<u32>() > 190;
+        let minimal = ddmin(items.clone(), large_sum);
+        assert!(large_sum(&minimal));
+        assert!((0..minimal.len()).all(|i| !large_sum(&[&minimal[..i], &minimal[i + 1..]].concat())));
+
+        assert_eq!(ddmin(items, |_| true), vec![1]);
+    }
+
+    #[test]
+    fn test_minimize_lines() {
+        let lines = vec_of_strings!["0: 1 2", "1: \"a\"", "2: \"b\"", "", "ab", "ba", "aab", "", "trailer"];
+        let fails = |lines: &[String]| lines.iter().any(|l| l == "2: \"b\"") && lines.iter().any(|l| l == "ba");
+
+        assert_eq!(minimize_lines(lines.clone(), fails), vec_of_strings!["2: \"b\"", "ba"]);
+        assert_eq!(join_records(&split_records(&lines)), lines);
+        assert!(panics(|| panic!("boom")));
+        assert!(!panics(|| {}));
+    }
+}
@@ -0,0 +1,134 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const BAR_WIDTH: u64 = 40;
+const RENDER_INTERVAL: Duration = Duration::from_millis(100);
+
+pub trait Reporter: Send + Sync {
+    // `total` is None for open-ended loops that only know their current step
+    fn report(&self, current: u64, total: Option<u64>);
+
+    fn finish(&self);
+}
+
+static REPORTER: OnceLock<Box<dyn Reporter>> = OnceLock::new();
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_reporter(reporter: Box<dyn Reporter>) -> bool {
+    let installed = REPORTER.set(reporter).is_ok();
+    if installed {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    installed
+}
+
+pub fn report(current: u64, total: u64) {
+    if ENABLED.load(Ordering::Relaxed) {
+        if let Some(reporter) = REPORTER.get() {
+            reporter.report(current, Some(total));
+        }
+    }
+}
+
+pub fn step(current: u64) {
+    if ENABLED.load(Ordering::Relaxed) {
+        if let Some(reporter) = REPORTER.get() {
+            reporter.report(current, None);
+        }
+    }
+}
+
+pub fn finish() {
+    if let Some(reporter) = REPORTER.get() {
+        reporter.finish();
+    }
+}
+
+// renders to stderr, skipping calls until the bar would visibly move so tight loops stay cheap
+pub struct ProgressBar {
+    next: AtomicU64,
+    last_render: Mutex<Option<Instant>>,
+}
+
+impl ProgressBar {
+    pub fn new() -> Self {
+        ProgressBar {
+            next: AtomicU64::new(0),
+            last_render: Mutex::new(None),
+        }
+    }
+
+    fn render(current: u64, total: Option<u64>) -> String {
+        match total {
+            Some(total) if total > 0 => {
+                let current = current.min(total);
+                let filled = (current * BAR_WIDTH / total) as usize;
+
+                format!(
+                    "[{}{}] {:>3}% {}/{}",
+                    "#".repeat(filled),
+                    ".".repeat(BAR_WIDTH as usize - filled),
+                    current * 100 / total,
+                    current,
+                    total,
+                )
+            }
+            _ => format!("step {}", current),
+        }
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        ProgressBar::new()
+    }
+}
+
+impl Reporter for ProgressBar {
+    fn report(&self, current: u64, total: Option<u64>) {
+        if current < self.next.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let stride = match total {
+            Some(total) => (total / (BAR_WIDTH * 25)).max(1),
+            None => 1,
+        };
+        self.next.store(current + stride, Ordering::Relaxed);
+
+        let mut last_render = self.last_render.lock().unwrap();
+        if total.is_none() && last_render.is_some_and(|t| t.elapsed() < RENDER_INTERVAL) {
+            return;
+        }
+        *last_render = Some(Instant::now());
+
+        eprint!("\r{}", ProgressBar::render(current, total));
+        io::stderr().flush().ok();
+    }
+
+    fn finish(&self) {
+        let mut last_render = self.last_render.lock().unwrap();
+
+        if last_render.take().is_some() {
+            eprint!("\r\x1b[2K");
+            io::stderr().flush().ok();
+        }
+        self.next.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(ProgressBar::render(0, Some(10)), format!("[{}]   0% 0/10", ".".repeat(40)));
+        assert_eq!(ProgressBar::render(5, Some(10)), format!("[{}{}]  50% 5/10", "#".repeat(20), ".".repeat(20)));
+        assert_eq!(ProgressBar::render(12, Some(10)), format!("[{}] 100% 10/10", "#".repeat(40)));
+        assert_eq!(ProgressBar::render(42, None), "step 42");
+    }
+}
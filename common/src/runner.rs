@@ -0,0 +1,423 @@
+use crate::answer::Answer;
+use crate::budget::{Budget, Exhausted};
+use crate::explain;
+use crate::inputs;
+use crate::minimize::{self, Failure};
+use crate::progress::{self, ProgressBar};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{info_span, warn};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+const USAGE: &str = "usage: dayNN [-q | --quiet] [-v | -vv | -vvv | --verbose] [--timeout <secs>] [--max-steps <n>] [--json] [--explain] [--dump-input] [--minimize <panic|timeout|mismatch>] [--set <key>=<value>]...";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Options {
+    pub verbosity: i8,
+    pub timeout: Option<Duration>,
+    pub max_steps: Option<u64>,
+    pub json: bool,
+    pub explain: bool,
+    pub dump_input: bool,
+    pub minimize: Option<Failure>,
+    pub settings: Vec<(String, String)>,
+}
+
+impl Options {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-q" | "--quiet" => options.verbosity = -1,
+                "--verbose" => options.verbosity = options.verbosity.saturating_add(1),
+                "--timeout" => {
+                    let secs: f64 = value_of(&arg, args.next())?;
+                    let timeout = Duration::try_from_secs_f64(secs)
+                        .map_err(|_| format!("Invalid value for argument {}: {}", arg, secs))?;
+
+                    options.timeout = Some(timeout);
+                }
+                "--max-steps" => options.max_steps = Some(value_of(&arg, args.next())?),
+                "--json" => options.json = true,
+                "--explain" => options.explain = true,
+                "--dump-input" => options.dump_input = true,
+                "--minimize" => options.minimize = Some(value_of(&arg, args.next())?),
+                "--set" => {
+                    let setting: String = value_of(&arg, args.next())?;
+                    let (key, value) = setting.split_once('=')
+                        .ok_or(format!("Invalid value for argument {}: {} (expected <key>=<value>)", arg, setting))?;
+
+                    options.settings.push((key.to_string(), value.to_string()));
+                }
+                s if s.len() > 1 && s.starts_with('-') && s[1..].chars().all(|c| c == 'v') => {
+                    let count = i8::try_from(s.len() - 1).unwrap_or(i8::MAX);
+
+                    options.verbosity = options.verbosity.saturating_add(count);
+                }
+                x => return Err(format!("Unknown argument: {}", x)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    pub fn level(&self) -> LevelFilter {
+        match self.verbosity {
+            i8::MIN..=-1 => LevelFilter::OFF,
+            0 => LevelFilter::WARN,
+            1 => LevelFilter::INFO,
+            2 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        }
+    }
+
+    pub fn budget(&self) -> Budget {
+        Budget::new(self.timeout, self.max_steps)
+    }
+}
+
+fn value_of<T: FromStr>(arg: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or(format!("Missing value for argument: {}", arg))?;
+
+    match value.parse::<T>() {
+        Ok(v) if !value.starts_with('-') => Ok(v),
+        _ => Err(format!("Invalid value for argument {}: {}", arg, value)),
+    }
+}
+
+type Solver<'a> = dyn Fn(&[String]) -> Answer + 'a;
+type BudgetedSolver<'a> = dyn Fn(&[String], &Budget) -> Result<Answer, Exhausted> + 'a;
+
+pub struct Runner {
+    day: &'static str,
+    options: Options,
+    explanations: RefCell<Vec<(String, Vec<String>)>>,
+    used_settings: RefCell<BTreeSet<String>>,
+}
+
+impl Runner {
+    pub fn new(day: &'static str) -> Self {
+        Runner::with_args(day, env::args().skip(1))
+    }
+
+    // for a binary running more than one day, whose own arguments come before the day's
+    pub fn with_args<I: IntoIterator<Item = String>>(day: &'static str, args: I) -> Self {
+        let options = match Options::from_args(args) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("{}\n{}", e, USAGE);
+                process::exit(2);
+            }
+        };
+
+        tracing_subscriber::fmt()
+            .with_max_level(options.level())
+            .with_span_events(FmtSpan::CLOSE)
+            .with_writer(io::stderr)
+            .try_init()
+            .ok();
+
+        if io::stderr().is_terminal() && !options.json && options.verbosity >= 0 {
+            progress::set_reporter(Box::new(ProgressBar::new()));
+        }
+
+        if options.explain {
+            explain::enable();
+        }
+
+        Runner { day, options, explanations: RefCell::new(Vec::new()), used_settings: RefCell::new(BTreeSet::new()) }
+    }
+
+    // a day-specific --set key=value, where the last one given wins and a value that doesn't parse is a usage error
+    pub fn setting<T: FromStr>(&self, key: &str) -> Option<T>
+    where
+        T::Err: fmt::Display,
+    {
+        self.used_settings.borrow_mut().insert(key.to_string());
+
+        let value = self.options.settings.iter().rev().find(|(k, _)| k == key).map(|(_, value)| value)?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(e) => {
+                eprintln!("Invalid value for setting {}: {} ({})\n{}", key, value, e, USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    pub fn input_lines(&self) -> Vec<String> {
+        inputs::get_input_lines(self.day)
+    }
+
+    pub fn input_string(&self) -> String {
+        inputs::get_input_as_string(self.day)
+    }
+
+    // with --dump-input, prints what the day's parser produced and exits before solving
+    pub fn dump_input<T: Serialize>(&self, parse: impl FnOnce() -> T) {
+        if !self.options.dump_input {
+            return;
+        }
+
+        let parsed = info_span!("dump_input", day = self.day).in_scope(parse);
+        match serde_json::to_string_pretty(&parsed) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Couldn't serialize parsed input: {}", e);
+                process::exit(1);
+            }
+        }
+
+        process::exit(0);
+    }
+
+    // with --minimize, shrinks the input while the chosen failure still reproduces, writes it out and exits
+    pub fn minimize_input(&self, run: impl Fn(&[String], &Budget) -> Result<Answer, Exhausted>) {
+        self.minimize(&run, None);
+    }
+
+    // like minimize_input, with a reference implementation to check for mismatches against
+    pub fn minimize_input_against(
+        &self,
+        run: impl Fn(&[String], &Budget) -> Result<Answer, Exhausted>,
+        reference: impl Fn(&[String]) -> Answer,
+    ) {
+        self.minimize(&run, Some(&reference));
+    }
+
+    // for days with no input file, so --minimize is refused rather than silently ignored
+    pub fn reject_minimize(&self, reason: &str) {
+        if self.options.minimize.is_some() {
+            eprintln!("{} has no input to minimize: {}\n{}", self.day, reason, USAGE);
+            process::exit(2);
+        }
+    }
+
+    fn minimize(&self, run: &BudgetedSolver<'_>, reference: Option<&Solver<'_>>) {
+        let failure = match self.options.minimize {
+            Some(failure) => failure,
+            None => return,
+        };
+
+        if failure == Failure::Timeout && self.options.timeout.is_none() && self.options.max_steps.is_none() {
+            eprintln!("--minimize timeout needs --timeout or --max-steps\n{}", USAGE);
+            process::exit(2);
+        }
+        if failure == Failure::Mismatch && reference.is_none() {
+            eprintln!("{} has no reference implementation to compare against", self.day);
+            process::exit(2);
+        }
+
+        let fails = |lines: &[String]| {
+            let budget = self.options.budget();
+            let mut result = None;
+            let panicked = minimize::panics(|| result = Some(run(lines, &budget)));
+
+            match (failure, result) {
+                (Failure::Panic, _) => panicked,
+                (Failure::Timeout, Some(result)) => result.is_err(),
+                (Failure::Mismatch, Some(Ok(answer))) => {
+                    let mut expected = None;
+                    minimize::panics(|| expected = reference.map(|reference| reference(lines)));
+
+                    expected.is_some_and(|expected| expected != answer)
+                }
+                _ => false,
+            }
+        };
+
+        let lines = self.input_lines();
+        let span = info_span!("minimize", day = self.day, %failure);
+        let reproduces = span.in_scope(|| fails(&lines));
+        let minimal = reproduces.then(|| span.in_scope(|| minimize::minimize_lines(lines.clone(), &fails)));
+        progress::finish();
+        explain::take();
+
+        let minimal = match minimal {
+            Some(minimal) => minimal,
+            None => {
+                eprintln!("{} input doesn't reproduce a {}", self.day, failure);
+                process::exit(1);
+            }
+        };
+
+        let path = minimize::reproducer_path(self.day);
+        if let Err(e) = minimize::write_reproducer(&path, &minimal) {
+            eprintln!("Couldn't write {}: {}", path, e);
+            process::exit(1);
+        }
+        println!("minimised {} lines to {}: {}", lines.len(), minimal.len(), path);
+
+        process::exit(0);
+    }
+
+    pub fn part(&self, part: u8, solve: impl FnOnce() -> Answer) -> Answer {
+        let answer = info_span!("part", day = self.day, part).in_scope(solve);
+        progress::finish();
+
+        self.print(json!(part), Ok(&answer), explain::take());
+
+        answer
+    }
+
+    // for a day's extra modes, printed like a part but under their own name
+    pub fn answer(&self, name: &str, solve: impl FnOnce() -> Answer) -> Answer {
+        let answer = info_span!("answer", day = self.day, name).in_scope(solve);
+        progress::finish();
+
+        self.print(json!(name), Ok(&answer), explain::take());
+
+        answer
+    }
+
+    // each budgeted part gets a fresh budget, and an exhausted one is reported in place of the answer
+    pub fn budgeted_part(&self, part: u8, solve: impl FnOnce(&Budget) -> Result<Answer, Exhausted>) -> Option<Answer> {
+        let budget = self.options.budget();
+        let result = info_span!("part", day = self.day, part).in_scope(|| solve(&budget));
+        progress::finish();
+
+        if let Err(e) = &result {
+            warn!(steps = budget.steps(), "{}", e);
+        }
+        self.print(json!(part), result.as_ref().map_err(|&e| e), explain::take());
+
+        result.ok()
+    }
+
+    // parts are labelled by number and extra modes by name
+    fn print(&self, part: Value, result: Result<&Answer, Exhausted>, facts: Vec<String>) {
+        if !self.options.json {
+            let label = match &part {
+                Value::String(name) => name.clone(),
+                part => format!("part{}", part),
+            };
+            match result {
+                Ok(answer) if answer.to_string().contains('\n') => println!("{}:\n{}", label, answer),
+                Ok(answer) => println!("{}: {}", label, answer),
+                Err(e) => println!("{}: timed out ({})", label, e),
+            }
+            if self.options.explain {
+                self.explanations.borrow_mut().push((label, facts));
+            }
+
+            return;
+        }
+
+        let mut output = json!({ "day": self.day, "part": part });
+        let fields = match result {
+            Ok(answer) => answer.to_json(),
+            Err(e) => json!({ "answer": null, "timed_out": e.to_string() }),
+        };
+        if let (Some(output), Value::Object(fields)) = (output.as_object_mut(), fields) {
+            output.extend(fields);
+            if self.options.explain {
+                output.insert("explanation".to_string(), json!(facts));
+            }
+        }
+
+        println!("{}", output);
+    }
+}
+
+// explanations are narrated once every answer has been printed
+impl Drop for Runner {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            return;
+        }
+
+        for (key, _) in &self.options.settings {
+            if !self.used_settings.borrow().contains(key) {
+                eprintln!("{} has no setting {}; it was ignored", self.day, key);
+            }
+        }
+
+        for (label, facts) in self.explanations.borrow().iter() {
+            println!("\n{} explanation:", label);
+
+            if facts.is_empty() {
+                println!("  (nothing to explain)");
+            }
+            for fact in facts {
+                println!("  {}", fact);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_from_args() {
+        assert_eq!(Options::from_args(vec_of_strings![]), Ok(Options::default()));
+        assert_eq!(Options::from_args(vec_of_strings!["-v"]), Ok(Options { verbosity: 1, ..Options::default() }));
+        assert_eq!(Options::from_args(vec_of_strings!["-vv", "--verbose"]), Ok(Options { verbosity: 3, ..Options::default() }));
+        assert_eq!(Options::from_args(vec_of_strings!["-q"]), Ok(Options { verbosity: -1, ..Options::default() }));
+        assert_eq!(
+            Options::from_args(vec!["-".to_string() + &"v".repeat(300), "--verbose".to_string()]),
+            Ok(Options { verbosity: i8::MAX, ..Options::default() })
+        );
+        assert_eq!(Options::from_args(vec_of_strings!["--json"]), Ok(Options { json: true, ..Options::default() }));
+        assert_eq!(Options::from_args(vec_of_strings!["--explain"]), Ok(Options { explain: true, ..Options::default() }));
+        assert_eq!(Options::from_args(vec_of_strings!["--dump-input"]), Ok(Options { dump_input: true, ..Options::default() }));
+        assert_eq!(
+            Options::from_args(vec_of_strings!["--minimize", "mismatch"]),
+            Ok(Options { minimize: Some(Failure::Mismatch), ..Options::default() })
+        );
+        assert!(Options::from_args(vec_of_strings!["--minimize", "crash"]).is_err());
+        assert_eq!(
+            Options::from_args(vec_of_strings!["--set", "slopes=1,1 3,1", "--set", "report="]),
+            Ok(Options {
+                settings: vec![("slopes".to_string(), "1,1 3,1".to_string()), ("report".to_string(), String::new())],
+                ..Options::default()
+            })
+        );
+        assert!(Options::from_args(vec_of_strings!["--set", "slopes"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_options_from_args_budget() {
+        assert_eq!(
+            Options::from_args(vec_of_strings!["--timeout", "1.5", "--max-steps", "1000"]),
+            Ok(Options { timeout: Some(Duration::from_millis(1500)), max_steps: Some(1000), ..Options::default() })
+        );
+        assert!(Options::from_args(vec_of_strings!["--timeout"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--timeout", "-1"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--timeout", "inf"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--timeout", "NaN"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--timeout", "1e300"]).is_err());
+        assert!(Options::from_args(vec_of_strings!["--max-steps", "lots"]).is_err());
+    }
+
+    #[test]
+    fn test_setting() {
+        let runner = Runner::with_args("day00", vec_of_strings!["-q", "--set", "n=1", "--set", "n=2", "--set", "name=x"]);
+
+        assert_eq!(runner.setting::<u32>("n"), Some(2));
+        assert_eq!(runner.setting::<String>("name"), Some("x".to_string()));
+        assert_eq!(runner.setting::<u32>("missing"), None);
+    }
+
+    #[test]
+    fn test_options_level() {
+        assert_eq!(Options { verbosity: -1, ..Options::default() }.level(), LevelFilter::OFF);
+        assert_eq!(Options::default().level(), LevelFilter::WARN);
+        assert_eq!(Options { verbosity: 2, ..Options::default() }.level(), LevelFilter::DEBUG);
+        assert_eq!(Options { verbosity: 5, ..Options::default() }.level(), LevelFilter::TRACE);
+    }
+}
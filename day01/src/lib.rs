@@ -0,0 +1,455 @@
+use common::answer::Answer;
+use common::arith::Overflow;
+use common::explain;
+use common::runner::Runner;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_entries(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(lines, 2020);
+        Ok(solve_part2(lines, 2020))
+    });
+    runner.part(1, || solve_part1(&lines, 2020));
+    runner.part(2, || solve_part2(&lines, 2020));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+struct Entry {
+    line: usize,
+    amount: i64,
+}
+
+// blank lines are skipped; anything else that isn't a whole number is reported with its line number
+fn parse_entries(lines: &[String]) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        match line.trim() {
+            "" => continue,
+            s => match s.parse::<i64>() {
+                Ok(amount) => entries.push(Entry { line: i + 1, amount }),
+                Err(e) => errors.push(format!("line {}: couldn't parse {:?} ({})", i + 1, s, e)),
+            },
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(entries),
+        false => Err(errors.join("; ")),
+    }
+}
+
+fn solve_part1(lines: &[String], target: i64) -> Answer {
+    solve(lines, 2, target)
+}
+
+fn solve_part2(lines: &[String], target: i64) -> Answer {
+    solve(lines, 3, target)
+}
+
+fn solve(lines: &[String], k: usize, target: i64) -> Answer {
+    let entries = match debug_span!("parse").in_scope(|| parse_entries(lines)) {
+        Ok(entries) => entries,
+        Err(e) => return Answer::none(e),
+    };
+    let nums = entries.iter().map(|e| e.amount).collect::<Vec<_>>();
+
+    match debug_span!("k_sum", k).in_scope(|| k_sum(&nums, k, target)) {
+        Some(found) => {
+            let described = found.iter()
+                .map(|&(i, n)| format!("{} (line {})", n, entries[i].line))
+                .collect::<Vec<_>>();
+            explain!("entries {} sum to {}", join_and(&described), target);
+            explain!("combinations of {} entries summing to {}: {}", k, target, count_k_sums(&nums, k, target));
+
+            product(found.iter().map(|&(_, n)| n)).map_or_else(Answer::from, Answer::from)
+        }
+        None => Answer::none(format!("no {} entries sum to {}", count_word(k), target)),
+    }
+}
+
+// three i64 amounts can already outgrow an i128
+fn product(nums: impl IntoIterator<Item = i64>) -> Result<i128, Overflow> {
+    nums.into_iter().try_fold(1i128, |acc, n| acc.checked_mul(n as i128).ok_or(Overflow("product")))
+}
+
+fn count_word(k: usize) -> String {
+    match k {
+        2 => "two".to_string(),
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        k => k.to_string(),
+    }
+}
+
+fn join_and(items: &[String]) -> String {
+    match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        _ => items.join(""),
+    }
+}
+
+// k entries at distinct indices summing to target, as (index, entry) pairs in index order
+pub fn k_sum(nums: &[i64], k: usize, target: i64) -> Option<Vec<(usize, i64)>> {
+    let mut sorted = nums.iter().enumerate().map(|(i, &n)| (n, i)).collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    let mut found = k_sum_sorted(&sorted, k, target as i128)?
+        .into_iter()
+        .map(|(n, i)| (i, n))
+        .collect::<Vec<_>>();
+    found.sort_unstable();
+
+    Some(found)
+}
+
+// sorted by entry, so the smallest remaining entry bounds everything after it;
+// k = 2 is a two-pointer scan, k = 4 hashes pair sums, and larger k fix one entry at a time
+fn k_sum_sorted(sorted: &[(i64, usize)], k: usize, target: i128) -> Option<Vec<(i64, usize)>> {
+    match k {
+        0 => (target == 0).then(Vec::new),
+        1 => sorted.binary_search_by_key(&target, |&(n, _)| n as i128).ok().map(|i| vec![sorted[i]]),
+        2 => two_sum(sorted, target),
+        4 => four_sum(sorted, target),
+        _ => sorted.iter()
+            .enumerate()
+            .take_while(|&(_, &(n, _))| n as i128 * k as i128 <= target)
+            .find_map(|(i, &entry)| {
+                let mut found = k_sum_sorted(&sorted[i + 1..], k - 1, target - entry.0 as i128)?;
+                found.insert(0, entry);
+
+                Some(found)
+            }),
+    }
+}
+
+fn two_sum(sorted: &[(i64, usize)], target: i128) -> Option<Vec<(i64, usize)>> {
+    let (mut lo, mut hi) = (0, sorted.len().checked_sub(1)?);
+
+    while lo < hi {
+        match (sorted[lo].0 as i128 + sorted[hi].0 as i128).cmp(&target) {
+            Ordering::Less => lo += 1,
+            Ordering::Greater => hi -= 1,
+            Ordering::Equal => return Some(vec![sorted[lo], sorted[hi]]),
+        }
+    }
+
+    None
+}
+
+// pairs only go into the map once b has moved past them, so a match never shares an entry with (b, c)
+fn four_sum(sorted: &[(i64, usize)], target: i128) -> Option<Vec<(i64, usize)>> {
+    let mut pairs: HashMap<i128, (usize, usize)> = HashMap::new();
+
+    for b in 0..sorted.len() {
+        for c in b + 1..sorted.len() {
+            if let Some(&(a1, a2)) = pairs.get(&(target - sorted[b].0 as i128 - sorted[c].0 as i128)) {
+                return Some(vec![sorted[a1], sorted[a2], sorted[b], sorted[c]]);
+            }
+        }
+        for a in 0..b {
+            pairs.entry(sorted[a].0 as i128 + sorted[b].0 as i128).or_insert((a, b));
+        }
+    }
+
+    None
+}
+
+// every set of k distinct indices whose entries sum to target, found lazily; equal entries on
+// different lines make different combinations, but each set of indices is produced only once
+pub struct KSums {
+    sorted: Vec<(i64, usize)>,
+    k: usize,
+    target: i128,
+    chosen: Vec<usize>,
+    sum: i128,
+    next: usize,
+    done: bool,
+}
+
+pub fn k_sums(nums: &[i64], k: usize, target: i64) -> KSums {
+    let mut sorted = nums.iter().enumerate().map(|(i, &n)| (n, i)).collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    KSums { sorted, k, target: target as i128, chosen: Vec::with_capacity(k), sum: 0, next: 0, done: false }
+}
+
+impl KSums {
+    fn combination(&self) -> Vec<(usize, i64)> {
+        let mut found = self.chosen.iter()
+            .map(|&p| (self.sorted[p].1, self.sorted[p].0))
+            .collect::<Vec<_>>();
+        found.sort_unstable();
+
+        found
+    }
+
+    fn backtrack(&mut self) {
+        match self.chosen.pop() {
+            Some(p) => {
+                self.sum -= self.sorted[p].0 as i128;
+                self.next = p + 1;
+            }
+            None => self.done = true,
+        }
+    }
+}
+
+// a depth-first walk over positions in the sorted entries, picking them in increasing order
+impl Iterator for KSums {
+    type Item = Vec<(usize, i64)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let remaining = self.k - self.chosen.len();
+
+            if remaining == 0 {
+                let found = (self.sum == self.target).then(|| self.combination());
+                self.backtrack();
+
+                match found {
+                    Some(found) => return Some(found),
+                    None => continue,
+                }
+            }
+
+            // only an entry equal to what's left can finish the combination
+            if remaining == 1 {
+                let needed = self.target - self.sum;
+                self.next += self.sorted[self.next..].partition_point(|&(n, _)| (n as i128) < needed);
+            }
+
+            match self.sorted.get(self.next) {
+                Some(&(n, _)) if self.sorted.len() - self.next >= remaining && self.sum + n as i128 * remaining as i128 <= self.target => {
+                    self.chosen.push(self.next);
+                    self.sum += n as i128;
+                    self.next += 1;
+                }
+                _ => self.backtrack(),
+            }
+        }
+
+        None
+    }
+}
+
+// how many items k_sums would produce, without building any of them
+pub fn count_k_sums(nums: &[i64], k: usize, target: i64) -> u64 {
+    let mut sorted = nums.to_vec();
+    sorted.sort_unstable();
+
+    count_sorted(&sorted, k, target as i128)
+}
+
+fn count_sorted(sorted: &[i64], k: usize, target: i128) -> u64 {
+    match k {
+        0 => (target == 0) as u64,
+        1 => {
+            let below = sorted.partition_point(|&n| (n as i128) < target);
+
+            (sorted.partition_point(|&n| n as i128 <= target) - below) as u64
+        }
+        _ => sorted.iter()
+            .enumerate()
+            .take_while(|&(_, &n)| n as i128 * k as i128 <= target)
+            .map(|(i, &n)| count_sorted(&sorted[i + 1..], k - 1, target - n as i128))
+            .sum(),
+    }
+}
+
+// answers k-sum queries as entries arrive: each push reports the combinations it completes.
+// keeps the sums of every subset of up to k - 1 earlier entries, so it suits small k
+pub struct KSumStream {
+    k: usize,
+    target: i128,
+    entries: Vec<i64>,
+    partial: Vec<HashMap<i128, Vec<Vec<usize>>>>,
+    matches: u64,
+}
+
+impl KSumStream {
+    pub fn new(k: usize, target: i64) -> Self {
+        let mut partial = vec![HashMap::new(); k.max(1)];
+        partial[0].insert(0, vec![Vec::new()]);
+
+        KSumStream { k, target: target as i128, entries: Vec::new(), partial, matches: 0 }
+    }
+
+    pub fn push(&mut self, n: i64) -> Vec<Vec<(usize, i64)>> {
+        let index = self.entries.len();
+        self.entries.push(n);
+
+        if self.k == 0 {
+            return Vec::new();
+        }
+
+        let completed = self.partial[self.k - 1]
+            .get(&(self.target - n as i128))
+            .map_or_else(Vec::new, |subsets| {
+                subsets.iter()
+                    .map(|subset| subset.iter().chain(std::iter::once(&index)).map(|&i| (i, self.entries[i])).collect())
+                    .collect::<Vec<_>>()
+            });
+        self.matches += completed.len() as u64;
+
+        // largest subsets first, so the new entry is never added twice to the same subset
+        for size in (1..self.k).rev() {
+            let extended = self.partial[size - 1].iter()
+                .map(|(&sum, subsets)| (sum + n as i128, subsets.clone()))
+                .collect::<Vec<_>>();
+
+            for (sum, subsets) in extended {
+                self.partial[size].entry(sum).or_default().extend(subsets.into_iter().map(|mut subset| {
+                    subset.push(index);
+                    subset
+                }));
+            }
+        }
+
+        completed
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // combinations completed so far, across every push
+    pub fn matches(&self) -> u64 {
+        self.matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "1721",
+            "979",
+            "366",
+            "299",
+            "675",
+            "1456",
+        ];
+
+        assert_eq!(solve_part1(&lines, 2020), Answer::from(514579));
+        assert_eq!(solve_part1(&lines, 2021), Answer::none("no two entries sum to 2021"));
+        assert_eq!(solve_part1(&lines, 99999), Answer::none("no two entries sum to 99999"));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "1721",
+            "979",
+            "366",
+            "299",
+            "675",
+            "1456",
+        ];
+
+        assert_eq!(solve_part2(&lines, 2020), Answer::from(241861950));
+        assert_eq!(solve_part1(&lines, 2021), Answer::none("no two entries sum to 2021"));
+        assert_eq!(solve_part1(&lines, 99999), Answer::none("no two entries sum to 99999"));
+    }
+
+    #[test]
+    fn test_k_sum() {
+        let nums = vec![1721, 979, 366, 299, 675, 1456];
+
+        assert_eq!(k_sum(&nums, 2, 2020), Some(vec![(0, 1721), (3, 299)]));
+        assert_eq!(k_sum(&nums, 3, 2020), Some(vec![(1, 979), (2, 366), (4, 675)]));
+        assert_eq!(k_sum(&nums, 4, 366 + 299 + 675 + 1456), Some(vec![(2, 366), (3, 299), (4, 675), (5, 1456)]));
+        assert_eq!(k_sum(&nums, 5, 1721 + 979 + 366 + 299 + 675), Some(vec![(0, 1721), (1, 979), (2, 366), (3, 299), (4, 675)]));
+        assert_eq!(k_sum(&nums, 1, 366), Some(vec![(2, 366)]));
+        assert_eq!(k_sum(&nums, 0, 0), Some(vec![]));
+        assert_eq!(k_sum(&nums, 7, 2020), None);
+    }
+
+    #[test]
+    fn test_k_sum_distinct_indices() {
+        assert_eq!(k_sum(&[1010, 5], 2, 2020), None);
+        assert_eq!(k_sum(&[1010, 5, 1010], 2, 2020), Some(vec![(0, 1010), (2, 1010)]));
+        assert_eq!(k_sum(&[505, 505, 505], 4, 2020), None);
+        assert_eq!(k_sum(&[505, 505, 505, 505], 4, 2020), Some(vec![(0, 505), (1, 505), (2, 505), (3, 505)]));
+    }
+
+    #[test]
+    fn test_k_sums() {
+        let nums = vec![5, 5, 10, 5, 0, 15];
+        let pairs = k_sums(&nums, 2, 15).collect::<Vec<_>>();
+
+        assert_eq!(pairs, vec![
+            vec![(4, 0), (5, 15)],
+            vec![(0, 5), (2, 10)],
+            vec![(1, 5), (2, 10)],
+            vec![(2, 10), (3, 5)],
+        ]);
+        assert_eq!(count_k_sums(&nums, 2, 15), 4);
+        assert_eq!(k_sums(&nums, 3, 15).count(), 4);
+        assert_eq!(count_k_sums(&nums, 3, 15), 4);
+        assert_eq!(k_sums(&nums, 2, 10).collect::<Vec<_>>(), vec![
+            vec![(2, 10), (4, 0)],
+            vec![(0, 5), (1, 5)],
+            vec![(0, 5), (3, 5)],
+            vec![(1, 5), (3, 5)],
+        ]);
+        assert_eq!(k_sums(&nums, 0, 0).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(k_sums(&nums, 7, 40).count(), 0);
+        assert_eq!(count_k_sums(&nums, 4, 1000), 0);
+    }
+
+    #[test]
+    fn test_signed_and_malformed_entries() {
+        let lines = vec_of_strings!["-5", "", "3000000000", "2025", "-2999997975"];
+
+        assert_eq!(solve_part1(&lines, 2020), Answer::from(-10125));
+        assert_eq!(solve_part2(&lines, 2020), Answer::from(-5i128 * 3000000000 * -2999997975));
+        let malformed = vec_of_strings!["12", "x", "", "1.5"];
+        assert_eq!(
+            solve_part1(&malformed, 2020),
+            Answer::none("line 2: couldn't parse \"x\" (invalid digit found in string); line 4: couldn't parse \"1.5\" (invalid digit found in string)")
+        );
+
+        let too_small = vec_of_strings![i64::MAX.to_string(), i64::MAX.to_string(), (2020 - 2 * i64::MAX as i128).to_string()];
+        assert_eq!(
+            solve_part2(&too_small, 2020),
+            Answer::none("line 3: couldn't parse \"-18446744073709549594\" (number too small to fit in target type)")
+        );
+
+        let huge = vec_of_strings![i64::MAX.to_string(), i64::MIN.to_string(), "2021"];
+        assert_eq!(solve_part2(&huge, 2020), Answer::none("arithmetic overflow in product"));
+    }
+
+    #[test]
+    fn test_k_sum_stream() {
+        let mut stream = KSumStream::new(2, 2020);
+
+        assert!(stream.push(1721).is_empty());
+        assert!(stream.push(979).is_empty());
+        assert_eq!(stream.push(299), vec![vec![(0, 1721), (2, 299)]]);
+        assert_eq!(stream.push(1041), vec![vec![(1, 979), (3, 1041)]]);
+        assert_eq!(stream.push(299), vec![vec![(0, 1721), (4, 299)]]);
+        assert_eq!((stream.len(), stream.matches()), (5, 3));
+
+        let mut stream = KSumStream::new(3, 0);
+        let completed = vec![-1, 2, -1, 0, 1].into_iter().map(|n| stream.push(n).len()).collect::<Vec<_>>();
+
+        assert_eq!(completed, vec![0, 0, 1, 0, 2]);
+        assert_eq!(stream.matches(), count_k_sums(&[-1, 2, -1, 0, 1], 3, 0));
+    }
+}
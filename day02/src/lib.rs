@@ -0,0 +1,767 @@
+use common::answer::Answer;
+use common::explain;
+use common::runner::Runner;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+use std::str;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    run_on(runner, &runner.input_lines());
+}
+
+const PART1_POLICY: &str = "count-range";
+const PART2_POLICY: &str = "positions";
+
+// --set part1-policy=... and part2-policy=... replace each part's policy with a registered one or a
+// combination of them, as PolicyRegistry::parse reads it. --set report=<policy> lists the passwords
+// that policy rejects instead of solving the parts
+fn run_on(runner: &Runner, lines: &[String]) -> Vec<Answer> {
+    let part1_policy = runner.setting::<String>("part1-policy").unwrap_or_else(|| PART1_POLICY.to_string());
+    let part2_policy = runner.setting::<String>("part2-policy").unwrap_or_else(|| PART2_POLICY.to_string());
+    let report_policy = runner.setting::<String>("report");
+
+    runner.dump_input(|| parse_records(lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(lines, &part1_policy);
+        Ok(solve_part2(lines, &part2_policy))
+    });
+
+    if let Some(policy) = report_policy {
+        return vec![runner.answer("report", || solve_report(lines, &PolicyRegistry::builtin(), &policy))];
+    }
+
+    vec![
+        runner.part(1, || solve_part1(lines, &part1_policy)),
+        runner.part(2, || solve_part2(lines, &part2_policy)),
+    ]
+}
+
+fn solve_part1(lines: &[String], policy: &str) -> Answer {
+    solve(lines, &PolicyRegistry::builtin(), policy)
+}
+
+fn solve_part2(lines: &[String], policy: &str) -> Answer {
+    solve(lines, &PolicyRegistry::builtin(), policy)
+}
+
+fn solve(lines: &[String], registry: &PolicyRegistry, policy: &str) -> Answer {
+    match report(lines, registry, policy) {
+        Ok(invalid) => {
+            explain!("{} of {} passwords break {}", invalid.len(), lines.len(), policy);
+            (lines.len() - invalid.len()).into()
+        }
+        Err(e) => Answer::none(e),
+    }
+}
+
+// one rejected password per line, as "line N: <entry>: <reason>"
+fn solve_report(lines: &[String], registry: &PolicyRegistry, policy: &str) -> Answer {
+    match report(lines, registry, policy) {
+        Ok(invalid) if invalid.is_empty() => Answer::Text(format!("{} allows every password", policy)),
+        Ok(invalid) => Answer::Text(
+            invalid.iter()
+                .map(|(line, violation)| format!("line {}: {}: {}", line, lines[line - 1], violation))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        Err(e) => Answer::none(e),
+    }
+}
+
+// every entry the named policy rejects, with its line number and why
+pub fn report(lines: &[String], registry: &PolicyRegistry, policy: &str) -> Result<Vec<(usize, Violation)>, String> {
+    let policy = registry.parse(policy)?;
+    let records = debug_span!("parse").in_scope(|| parse_records(lines))
+        .map_err(|(line, e)| format!("line {}: {}", line, e))?;
+
+    Ok(validate(&records, policy.as_ref()))
+}
+
+// every record the policy rejects, with its line number and why
+pub fn validate(records: &[Record<'_>], policy: &dyn Policy) -> Vec<(usize, Violation)> {
+    records.iter()
+        .enumerate()
+        .filter_map(|(i, r)| policy.check(r).err().map(|v| (i + 1, v)))
+        .collect()
+}
+
+// input is handed to the workers in newline-aligned chunks of about this many bytes
+const CHUNK_BYTES: usize = 1 << 20;
+
+#[derive(Debug)]
+pub enum BulkError {
+    Io(io::Error),
+    Parse(usize, ParseError),
+}
+
+impl fmt::Display for BulkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BulkError::Io(e) => write!(f, "couldn't read input: {}", e),
+            BulkError::Parse(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+// how many records each policy allows, in one pass over a reader of any size; lines are never copied
+// out of the chunk they were read into. a malformed input reports its earliest bad line
+pub fn count_valid(reader: impl Read, policies: &[&dyn Policy], threads: usize) -> Result<Vec<usize>, BulkError> {
+    count_valid_in_chunks(reader, policies, threads, CHUNK_BYTES)
+}
+
+fn count_valid_in_chunks(
+    reader: impl Read,
+    policies: &[&dyn Policy],
+    threads: usize,
+    chunk_bytes: usize,
+) -> Result<Vec<usize>, BulkError> {
+    let (sender, receiver) = mpsc::sync_channel::<(usize, Vec<u8>)>(threads.max(1) * 2);
+    let receiver = Mutex::new(receiver);
+
+    thread::scope(|scope| {
+        let workers = (0..threads.max(1))
+            .map(|_| scope.spawn(|| {
+                let mut counts = vec![0; policies.len()];
+                let mut error: Option<(usize, ParseError)> = None;
+
+                loop {
+                    // the lock is only held while waiting for the next chunk, not while checking it
+                    let next = receiver.lock().unwrap().recv();
+                    let (first_line, chunk) = match next {
+                        Ok(next) => next,
+                        Err(_) => break,
+                    };
+
+                    if let Err(e) = count_chunk(&chunk, first_line, policies, &mut counts) {
+                        error = error.into_iter().chain(std::iter::once(e)).min_by_key(|&(line, _)| line);
+                    }
+                }
+
+                (counts, error)
+            }))
+            .collect::<Vec<_>>();
+
+        let read = read_chunks(reader, chunk_bytes, |first_line, chunk| sender.send((first_line, chunk)).is_ok());
+        drop(sender);
+
+        let mut totals = vec![0; policies.len()];
+        let mut error: Option<(usize, ParseError)> = None;
+        for worker in workers {
+            let (counts, e) = worker.join().unwrap();
+
+            totals.iter_mut().zip(counts).for_each(|(total, n)| *total += n);
+            error = error.into_iter().chain(e).min_by_key(|&(line, _)| line);
+        }
+
+        read.map_err(BulkError::Io)?;
+        match error {
+            Some((line, e)) => Err(BulkError::Parse(line, e)),
+            None => Ok(totals),
+        }
+    })
+}
+
+// reads whole lines at a time, carrying any partial last line over to the next chunk
+fn read_chunks(mut reader: impl Read, chunk_bytes: usize, mut send: impl FnMut(usize, Vec<u8>) -> bool) -> io::Result<()> {
+    let mut chunk = Vec::new();
+    let mut first_line = 1;
+
+    loop {
+        let start = chunk.len();
+        chunk.resize(start + chunk_bytes.max(1), 0);
+        let read = loop {
+            match reader.read(&mut chunk[start..]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                read => break read?,
+            }
+        };
+        chunk.truncate(start + read);
+
+        if read == 0 {
+            if !chunk.is_empty() {
+                send(first_line, chunk);
+            }
+            return Ok(());
+        }
+
+        if let Some(end) = chunk.iter().rposition(|&b| b == b'\n') {
+            let rest = chunk.split_off(end + 1);
+            let lines = chunk.iter().filter(|&&b| b == b'\n').count();
+
+            if !send(first_line, std::mem::replace(&mut chunk, rest)) {
+                return Ok(());
+            }
+            first_line += lines;
+        }
+    }
+}
+
+fn count_chunk(chunk: &[u8], first_line: usize, policies: &[&dyn Policy], counts: &mut [usize]) -> Result<(), (usize, ParseError)> {
+    let chunk = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+
+    for (i, line) in chunk.split(|&b| b == b'\n').enumerate() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let record = Record::parse(line).map_err(|e| (first_line + i, e))?;
+
+        for (count, policy) in counts.iter_mut().zip(policies) {
+            if policy.allows(&record) {
+                *count += 1;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// stops at the first malformed line, returned with its line number
+fn parse_records(lines: &[String]) -> Result<Vec<Record<'_>>, (usize, ParseError)> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, l)| Record::parse(l.as_bytes()).map_err(|e| (i + 1, e)))
+        .collect()
+}
+
+// one line of the database; what the two numbers and the letter mean is up to the policy applied to it.
+// the password borrows from the line, so checking a record never copies it
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Record<'a> {
+    pub n1: usize,
+    pub n2: usize,
+    pub letter: char,
+    pub password: &'a str,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum ParseError {
+    MissingPassword,
+    MissingLetter,
+    MissingRange(String),
+    InvalidNumber(String),
+    InvalidLetter(String),
+    InvalidUtf8,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingPassword => write!(f, "expected \"<policy>: <password>\""),
+            ParseError::MissingLetter => write!(f, "expected \"<n1>-<n2> <letter>\" before the colon"),
+            ParseError::MissingRange(s) => write!(f, "expected \"<n1>-<n2>\", found {:?}", s),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number {:?}", s),
+            ParseError::InvalidLetter(s) => write!(f, "expected a single letter, found {:?}", s),
+            ParseError::InvalidUtf8 => write!(f, "invalid UTF-8"),
+        }
+    }
+}
+
+// the letter and password may be any characters; the password is everything after the first ": ".
+// works on bytes so bulk input never has to be turned into Strings first
+impl<'a> Record<'a> {
+    pub fn parse(line: &'a [u8]) -> Result<Self, ParseError> {
+        let colon = line.windows(2).position(|w| w == b": ").ok_or(ParseError::MissingPassword)?;
+        let (policy, password) = (&line[..colon], &line[colon + 2..]);
+        if password.is_empty() {
+            return Err(ParseError::MissingPassword);
+        }
+
+        let space = policy.iter().position(|&b| b == b' ').ok_or(ParseError::MissingLetter)?;
+        let (range, letter) = (&policy[..space], &policy[space + 1..]);
+        let dash = range.iter().position(|&b| b == b'-')
+            .ok_or_else(|| ParseError::MissingRange(String::from_utf8_lossy(range).into_owned()))?;
+
+        let letter = str::from_utf8(letter).map_err(|_| ParseError::InvalidUtf8)?;
+        let mut chars = letter.chars();
+        let letter = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(ParseError::InvalidLetter(letter.to_string())),
+        };
+
+        Ok(Record {
+            n1: parse_number(&range[..dash])?,
+            n2: parse_number(&range[dash + 1..])?,
+            letter,
+            password: str::from_utf8(password).map_err(|_| ParseError::InvalidUtf8)?,
+        })
+    }
+}
+
+fn parse_number(bytes: &[u8]) -> Result<usize, ParseError> {
+    let digits = match bytes.is_empty() {
+        true => None,
+        false => bytes.iter().try_fold(0usize, |n, &b| match b {
+            b'0'..=b'9' => n.checked_mul(10)?.checked_add((b - b'0') as usize),
+            _ => None,
+        }),
+    };
+
+    digits.ok_or_else(|| ParseError::InvalidNumber(String::from_utf8_lossy(bytes).into_owned()))
+}
+
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.n1, self.n2, self.letter, self.password)
+    }
+}
+
+// why a password broke a policy, with what was actually observed
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    Count { letter: char, occurrences: usize, expected: RangeInclusive<usize> },
+    Positions { letter: char, positions: (usize, usize), found: (char, char) },
+    OutOfRange { position: usize, length: usize },
+    Mismatch { pattern: String },
+    Forbidden { letter: char },
+    Negated,
+    NoAlternative(Vec<Violation>),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::Count { letter, occurrences, expected } => {
+                write!(f, "letter {:?} occurs {} times, expected {}..={}", letter, occurrences, expected.start(), expected.end())
+            }
+            Violation::Positions { letter, positions, found: (c1, c2) } => write!(
+                f,
+                "positions {} and {} hold {:?} and {:?}, expected {:?} at exactly one",
+                positions.0, positions.1, c1, c2, letter
+            ),
+            Violation::OutOfRange { position, length } => {
+                write!(f, "position {} is outside the {} letter password", position, length)
+            }
+            Violation::Mismatch { pattern } => write!(f, "doesn't match /{}/", pattern),
+            Violation::Forbidden { letter } => write!(f, "contains forbidden letter {:?}", letter),
+            Violation::Negated => write!(f, "satisfies a policy it must not"),
+            Violation::NoAlternative(violations) => {
+                let reasons = violations.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+                write!(f, "no alternative holds: {}", reasons.join("; "))
+            }
+        }
+    }
+}
+
+pub trait Policy: Send + Sync {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation>;
+
+    fn allows(&self, record: &Record<'_>) -> bool {
+        self.check(record).is_ok()
+    }
+}
+
+// so a registered policy can also be part of a combination parsed from its name
+impl<P: Policy + ?Sized> Policy for Arc<P> {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        self.as_ref().check(record)
+    }
+}
+
+// the letter appears between n1 and n2 times
+pub struct CountRange;
+
+impl Policy for CountRange {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        let occurrences = record.password.matches(record.letter).count();
+        let expected = record.n1..=record.n2;
+
+        match expected.contains(&occurrences) {
+            true => Ok(()),
+            false => Err(Violation::Count { letter: record.letter, occurrences, expected }),
+        }
+    }
+}
+
+// the letter is at exactly one of the 1-based positions n1 and n2, counted in chars
+pub struct Positions;
+
+impl Policy for Positions {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        let length = record.password.chars().count();
+        let at = |position: usize| match position.checked_sub(1).and_then(|i| record.password.chars().nth(i)) {
+            Some(c) => Ok(c),
+            None => Err(Violation::OutOfRange { position, length }),
+        };
+        let found = (at(record.n1)?, at(record.n2)?);
+
+        match (found.0 == record.letter) != (found.1 == record.letter) {
+            true => Ok(()),
+            false => Err(Violation::Positions { letter: record.letter, positions: (record.n1, record.n2), found }),
+        }
+    }
+}
+
+pub struct Matches(pub Regex);
+
+impl Policy for Matches {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        match self.0.is_match(record.password) {
+            true => Ok(()),
+            false => Err(Violation::Mismatch { pattern: self.0.as_str().to_string() }),
+        }
+    }
+}
+
+pub struct Forbidden(pub Vec<char>);
+
+impl Policy for Forbidden {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        match record.password.chars().find(|c| self.0.contains(c)) {
+            Some(letter) => Err(Violation::Forbidden { letter }),
+            None => Ok(()),
+        }
+    }
+}
+
+// reports the first policy that fails
+pub struct All(pub Vec<Box<dyn Policy>>);
+
+impl Policy for All {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        self.0.iter().try_for_each(|p| p.check(record))
+    }
+}
+
+pub struct Any(pub Vec<Box<dyn Policy>>);
+
+impl Policy for Any {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        let mut violations = Vec::new();
+
+        for policy in &self.0 {
+            match policy.check(record) {
+                Ok(()) => return Ok(()),
+                Err(v) => violations.push(v),
+            }
+        }
+
+        Err(Violation::NoAlternative(violations))
+    }
+}
+
+pub struct Not(pub Box<dyn Policy>);
+
+impl Policy for Not {
+    fn check(&self, record: &Record<'_>) -> Result<(), Violation> {
+        match self.0.allows(record) {
+            true => Err(Violation::Negated),
+            false => Ok(()),
+        }
+    }
+}
+
+// policies by name, so the one to apply can be picked at runtime
+#[derive(Default)]
+pub struct PolicyRegistry {
+    policies: BTreeMap<String, Arc<dyn Policy>>,
+}
+
+impl PolicyRegistry {
+    pub fn new() -> Self {
+        PolicyRegistry { policies: BTreeMap::new() }
+    }
+
+    // the sled rental place's policy (part 1) and the toboggan corporate policy (part 2)
+    pub fn builtin() -> Self {
+        let mut registry = PolicyRegistry::new();
+        registry.register("count-range", CountRange);
+        registry.register("positions", Positions);
+
+        registry
+    }
+
+    pub fn register(&mut self, name: &str, policy: impl Policy + 'static) {
+        self.policies.insert(name.to_string(), Arc::new(policy));
+    }
+
+    pub fn get(&self, name: &str) -> Result<&dyn Policy, String> {
+        self.policies.get(name).map(|p| p.as_ref()).ok_or_else(|| {
+            format!("unknown password policy: {} (known: {})", name, self.names().collect::<Vec<_>>().join(", "))
+        })
+    }
+
+    // a registered name, or all(...), any(...) and not(...) around others, matches(<regex>) or forbidden(<letters>),
+    // e.g. "all(count-range, not(positions))"
+    pub fn parse(&self, policy: &str) -> Result<Box<dyn Policy>, String> {
+        let policy = policy.trim();
+        let (name, argument) = match policy.find('(') {
+            Some(open) if policy.ends_with(')') => (policy[..open].trim(), Some(&policy[open + 1..policy.len() - 1])),
+            _ => (policy, None),
+        };
+
+        match (name, argument) {
+            ("all", Some(policies)) => Ok(Box::new(All(self.parse_list(policies)?))),
+            ("any", Some(policies)) => Ok(Box::new(Any(self.parse_list(policies)?))),
+            ("not", Some(policy)) => Ok(Box::new(Not(self.parse(policy)?))),
+            ("matches", Some(pattern)) => match Regex::new(pattern) {
+                Ok(regex) => Ok(Box::new(Matches(regex))),
+                Err(e) => Err(format!("invalid pattern in {}: {}", policy, e)),
+            },
+            ("forbidden", Some(letters)) => Ok(Box::new(Forbidden(letters.chars().collect()))),
+            (_, Some(_)) => {
+                Err(format!("unknown password policy combinator: {} (known: all, any, not, matches, forbidden)", name))
+            }
+            (_, None) => {
+                self.get(name)?;
+                Ok(Box::new(Arc::clone(&self.policies[name])))
+            }
+        }
+    }
+
+    // comma separated, leaving commas inside parentheses to the policy they belong to
+    fn parse_list(&self, policies: &str) -> Result<Vec<Box<dyn Policy>>, String> {
+        let mut depth = 0;
+        let mut start = 0;
+        let mut parsed = Vec::new();
+
+        for (i, c) in policies.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => return Err(format!("unbalanced parentheses in {}", policies)),
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    parsed.push(self.parse(&policies[start..i])?);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return Err(format!("unbalanced parentheses in {}", policies));
+        }
+        parsed.push(self.parse(&policies[start..])?);
+
+        Ok(parsed)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.policies.keys().map(|name| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+        ];
+
+        assert_eq!(solve_part1(&lines, PART1_POLICY), Answer::from(2));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+        ];
+
+        assert_eq!(solve_part2(&lines, PART2_POLICY), Answer::from(1));
+    }
+
+    #[test]
+    fn test_policy_registry() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+            "1-30 a: a",
+        ];
+        let mut registry = PolicyRegistry::builtin();
+        registry.register("no-vowels", Forbidden(vec!['a', 'e', 'i', 'o', 'u']));
+        registry.register("short", Matches(Regex::new("^.{1,5}$").unwrap()));
+        registry.register("either", Any(vec![Box::new(CountRange), Box::new(Positions)]));
+        registry.register("short-and-not-positions", All(vec![
+            Box::new(Matches(Regex::new("^.{1,5}$").unwrap())),
+            Box::new(Not(Box::new(Positions))),
+        ]));
+
+        assert_eq!(solve(&lines, &registry, "count-range"), Answer::from(3));
+        assert_eq!(solve(&lines, &registry, "positions"), Answer::from(1));
+        assert_eq!(solve(&lines, &registry, "no-vowels"), Answer::from(1));
+        assert_eq!(solve(&lines, &registry, "short"), Answer::from(3));
+        assert_eq!(solve(&lines, &registry, "either"), Answer::from(3));
+        assert_eq!(solve(&lines, &registry, "short-and-not-positions"), Answer::from(2));
+        assert_eq!(
+            solve(&lines, &registry, "nope"),
+            Answer::none("unknown password policy: nope (known: count-range, either, no-vowels, positions, short, short-and-not-positions)")
+        );
+        assert_eq!(solve(&lines, &registry, "all(matches(^.{1,5}$), not(positions))"), Answer::from(2));
+        assert_eq!(solve(&lines, &registry, "any(count-range, forbidden(aeiou))"), Answer::from(3));
+        assert_eq!(
+            solve(&lines, &registry, "all(short), positions)"),
+            Answer::none("unbalanced parentheses in short), positions")
+        );
+        assert_eq!(
+            solve(&lines, &registry, "none(positions)"),
+            Answer::none("unknown password policy combinator: none (known: all, any, not, matches, forbidden)")
+        );
+    }
+
+    #[test]
+    fn test_run_policies() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+        ];
+        let combined = Runner::with_args("day02", vec_of_strings![
+            "-q",
+            "--set", "part1-policy=not(count-range)",
+            "--set", "part2-policy=any(count-range, positions)",
+        ]);
+        let unknown = Runner::with_args("day02", vec_of_strings!["-q", "--set", "part2-policy=lengths"]);
+
+        assert_eq!(run_on(&combined, &lines), vec![Answer::from(1), Answer::from(2)]);
+        assert_eq!(run_on(&unknown, &lines), vec![
+            Answer::from(2),
+            Answer::none("unknown password policy: lengths (known: count-range, positions)"),
+        ]);
+    }
+
+    #[test]
+    fn test_report() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+            "4-5 a: abc",
+        ];
+        let registry = PolicyRegistry::builtin();
+        let runner = Runner::with_args("day02", vec_of_strings!["-q", "--set", "report=positions"]);
+
+        assert_eq!(report(&lines, &registry, "positions"), Ok(vec![
+            (2, Violation::Positions { letter: 'b', positions: (1, 3), found: ('c', 'e') }),
+            (3, Violation::Positions { letter: 'c', positions: (2, 9), found: ('c', 'c') }),
+            (4, Violation::OutOfRange { position: 4, length: 3 }),
+        ]));
+        assert_eq!(run_on(&runner, &lines), vec![Answer::Text([
+            "line 2: 1-3 b: cdefg: positions 1 and 3 hold 'c' and 'e', expected 'b' at exactly one",
+            "line 3: 2-9 c: ccccccccc: positions 2 and 9 hold 'c' and 'c', expected 'c' at exactly one",
+            "line 4: 4-5 a: abc: position 4 is outside the 3 letter password",
+        ].join("\n"))]);
+        assert_eq!(
+            solve_report(&lines[..1], &registry, "count-range"),
+            Answer::Text("count-range allows every password".to_string())
+        );
+        assert_eq!(
+            report(&lines, &registry, "nope"),
+            Err("unknown password policy: nope (known: count-range, positions)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_violations() {
+        let lines = vec_of_strings![
+            "1-3 a: abcde",
+            "1-3 b: cdefg",
+            "2-9 c: ccccccccc",
+        ];
+        let records = parse_records(&lines).unwrap();
+        let either = Any(vec![Box::new(Forbidden(vec!['d'])), Box::new(Matches(Regex::new("^a").unwrap()))]);
+
+        assert_eq!(validate(&records, &CountRange), vec![
+            (2, Violation::Count { letter: 'b', occurrences: 0, expected: 1..=3 }),
+        ]);
+        assert_eq!(validate(&records, &Positions), vec![
+            (2, Violation::Positions { letter: 'b', positions: (1, 3), found: ('c', 'e') }),
+            (3, Violation::Positions { letter: 'c', positions: (2, 9), found: ('c', 'c') }),
+        ]);
+        assert_eq!(
+            validate(&records, &CountRange)[0].1.to_string(),
+            "letter 'b' occurs 0 times, expected 1..=3"
+        );
+        assert_eq!(
+            validate(&records, &Positions)[1].1.to_string(),
+            "positions 2 and 9 hold 'c' and 'c', expected 'c' at exactly one"
+        );
+        assert_eq!(
+            either.check(&records[1]).unwrap_err().to_string(),
+            "no alternative holds: contains forbidden letter 'd'; doesn't match /^a/"
+        );
+        assert_eq!(Not(Box::new(CountRange)).check(&records[0]), Err(Violation::Negated));
+    }
+
+    proptest! {
+        #[test]
+        fn test_record_round_trip(n1 in any::<usize>(), n2 in any::<usize>(), letter in any::<char>(), password in ".{1,20}") {
+            let record = Record { n1, n2, letter, password: &password };
+            let line = record.to_string();
+
+            prop_assert_eq!(Record::parse(line.as_bytes()), Ok(record));
+        }
+    }
+
+    #[test]
+    fn test_unicode_and_malformed_records() {
+        let lines = vec_of_strings![
+            "1-2 é: éte",
+            "2-3 ß: Straße straße",
+            "1-3 Ω: ΩΩ",
+            "0-1 x: x",
+            "1-1 :: a: b",
+        ];
+        let records = parse_records(&lines).unwrap();
+
+        assert_eq!(records[1].password, "Straße straße");
+        assert_eq!(records[4], Record { n1: 1, n2: 1, letter: ':', password: "a: b" });
+        assert_eq!(solve_part1(&lines, PART1_POLICY), Answer::from(5));
+        assert_eq!(validate(&records, &Positions), vec![
+            (2, Violation::Positions { letter: 'ß', positions: (2, 3), found: ('t', 'r') }),
+            (3, Violation::OutOfRange { position: 3, length: 2 }),
+            (4, Violation::OutOfRange { position: 0, length: 1 }),
+            (5, Violation::Positions { letter: ':', positions: (1, 1), found: ('a', 'a') }),
+        ]);
+
+        assert_eq!(Record::parse(b"1-3 a abc"), Err(ParseError::MissingPassword));
+        assert_eq!(Record::parse(b"1-3 a: "), Err(ParseError::MissingPassword));
+        assert_eq!(Record::parse(b"1-3: abc"), Err(ParseError::MissingLetter));
+        assert_eq!(Record::parse(b"13 a: abc"), Err(ParseError::MissingRange("13".to_string())));
+        assert_eq!(Record::parse(b"1-+3 a: abc"), Err(ParseError::InvalidNumber("+3".to_string())));
+        assert_eq!(Record::parse(b"1-3 ab: abc"), Err(ParseError::InvalidLetter("ab".to_string())));
+
+        let malformed = vec_of_strings!["1-3 a: abc", "1-x a: abc"];
+        assert_eq!(solve_part1(&malformed, PART1_POLICY), Answer::none("line 2: invalid number \"x\""));
+    }
+
+    #[test]
+    fn test_count_valid() {
+        let input = (0..500)
+            .map(|i| format!("{}-{} {}: {}", i % 4 + 1, i % 7 + 2, ['a', 'é', 'z'][i % 3], "aéazzéa".repeat(i % 5 + 1)))
+            .collect::<Vec<_>>();
+        let records = parse_records(&input).unwrap();
+        let expected = [&CountRange as &dyn Policy, &Positions]
+            .iter()
+            .map(|p| records.iter().filter(|r| p.allows(r)).count())
+            .collect::<Vec<_>>();
+        let bytes = input.join("\r\n").into_bytes();
+
+        for &(threads, chunk_bytes) in &[(1, CHUNK_BYTES), (4, 7), (3, 1)] {
+            let counts = count_valid_in_chunks(&bytes[..], &[&CountRange, &Positions], threads, chunk_bytes).unwrap();
+
+            assert_eq!(counts, expected);
+        }
+
+        let malformed = b"1-3 a: abc\n1-3 a: abc\n1-x a: abc\n1-3 a: abc\n1-3 a abc\n";
+        match count_valid_in_chunks(&malformed[..], &[&CountRange], 2, 4) {
+            Err(BulkError::Parse(line, e)) => assert_eq!((line, e), (3, ParseError::InvalidNumber("x".to_string()))),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert_eq!(count_valid(&b""[..], &[&CountRange], 2).unwrap(), vec![0]);
+        assert_eq!(count_valid(&b"1-1 \xff: ab"[..], &[&CountRange], 2).unwrap_err().to_string(), "line 1: invalid UTF-8");
+    }
+}
@@ -0,0 +1,520 @@
+use common::answer::Answer;
+use common::runner::Runner;
+use common::explain;
+use serde::Serialize;
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+use tracing::{debug, debug_span, trace};
+
+pub fn run(runner: &Runner) {
+    run_on(runner, &runner.input_lines());
+}
+
+const PART1_SLOPES: &[Slope] = &[Slope { right: 3, down: 1 }];
+const PART2_SLOPES: &[Slope] = &[
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 },
+];
+
+// --set part1-slopes=... and part2-slopes=... replace each part's slopes. --set search=<fewest|most>:<right>,<down>
+// answers a search and --set render=<right>,<down> draws a slope's path, instead of the parts
+fn run_on(runner: &Runner, lines: &[String]) -> Vec<Answer> {
+    let part1_slopes = runner.setting::<Slopes>("part1-slopes").map_or_else(|| PART1_SLOPES.to_vec(), |s| s.0);
+    let part2_slopes = runner.setting::<Slopes>("part2-slopes").map_or_else(|| PART2_SLOPES.to_vec(), |s| s.0);
+    let search = runner.setting::<Search>("search");
+    let render_slope = runner.setting::<Slope>("render");
+
+    runner.dump_input(|| create_grid(lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(lines, &part1_slopes);
+        Ok(solve_part2(lines, &part2_slopes))
+    });
+
+    if let Some(search) = search {
+        return vec![runner.answer("search", || solve_search(lines, search))];
+    }
+    if let Some(slope) = render_slope {
+        return vec![runner.answer("render", || solve_render(lines, slope))];
+    }
+
+    vec![
+        runner.part(1, || solve_part1(lines, &part1_slopes)),
+        runner.part(2, || solve_part2(lines, &part2_slopes)),
+    ]
+}
+
+fn solve_part1(lines: &[String], slopes: &[Slope]) -> Answer {
+    solve(lines, slopes)
+}
+
+fn solve_part2(lines: &[String], slopes: &[Slope]) -> Answer {
+    solve(lines, slopes)
+}
+
+// the product of the trees hit on each slope in the list
+fn solve(lines: &[String], slopes: &[Slope]) -> Answer {
+    let grid = match debug_span!("parse").in_scope(|| create_grid(lines)) {
+        Ok(grid) => grid,
+        Err(e) => return Answer::none(e.to_string()),
+    };
+
+    let trees = traverse_grid(&grid, slopes);
+    for (slope, trees) in slopes.iter().zip(&trees) {
+        explain!("right {}, down {} hits {} trees", slope.right, slope.down, trees);
+        trace!(right = slope.right, down = slope.down, "path:\n{}", render(&grid, *slope).unwrap_or_else(|e| e));
+    }
+
+    // how the chosen slopes compare with everything up to their steepest
+    if explain::is_enabled() {
+        let max_right = slopes.iter().map(|s| s.right).max().unwrap_or(0);
+        let max_down = slopes.iter().map(|s| s.down).max().unwrap_or(0);
+
+        for &(goal, name) in &[(Goal::FewestTrees, "fewest"), (Goal::MostTrees, "most")] {
+            if let Some((slope, trees)) = search_slopes(&grid, max_right, max_down, goal) {
+                explain!(
+                    "{} trees up to right {}, down {}: right {}, down {} hits {}",
+                    name, max_right, max_down, slope.right, slope.down, trees
+                );
+            }
+        }
+    }
+
+    trees.iter().product::<i64>().into()
+}
+
+fn solve_render(lines: &[String], slope: Slope) -> Answer {
+    match debug_span!("parse").in_scope(|| create_grid(lines)) {
+        Ok(grid) => render(&grid, slope).map_or_else(Answer::none, Answer::Text),
+        Err(e) => Answer::none(e.to_string()),
+    }
+}
+
+fn solve_search(lines: &[String], search: Search) -> Answer {
+    let grid = match debug_span!("parse").in_scope(|| create_grid(lines)) {
+        Ok(grid) => grid,
+        Err(e) => return Answer::none(e.to_string()),
+    };
+
+    match search_slopes(&grid, search.max_right, search.max_down, search.goal) {
+        Some((slope, trees)) => Answer::Text(format!("right {}, down {} hits {} trees", slope.right, slope.down, trees)),
+        None => Answer::none("no slopes to search"),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct Slope {
+    right: usize,
+    down: usize,
+}
+
+// "right,down"; moving down by 0 would never leave the first row
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid slope {:?}, expected \"<right>,<down>\"", s);
+        let (right, down) = s.split_once(',').ok_or_else(invalid)?;
+
+        match (right.trim().parse::<usize>(), down.trim().parse::<usize>()) {
+            (Ok(right), Ok(down)) if down > 0 => Ok(Slope { right, down }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// whitespace separated, e.g. "1,1 3,1 1,2"
+#[derive(Clone, Debug, PartialEq)]
+struct Slopes(Vec<Slope>);
+
+impl FromStr for Slopes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().map(|slope| slope.parse::<Slope>()).collect::<Result<Vec<_>, _>>()? {
+            slopes if slopes.is_empty() => Err("No slopes given".to_string()),
+            slopes => Ok(Slopes(slopes)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Goal {
+    FewestTrees,
+    MostTrees,
+}
+
+// "<fewest|most>:<max right>,<max down>", e.g. "most:7,2"
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Search {
+    goal: Goal,
+    max_right: usize,
+    max_down: usize,
+}
+
+impl FromStr for Search {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid search {:?}, expected \"<fewest|most>:<max right>,<max down>\"", s);
+        let (goal, bounds) = s.split_once(':').ok_or_else(invalid)?;
+        let (max_right, max_down) = bounds.split_once(',').ok_or_else(invalid)?;
+
+        let goal = match goal {
+            "fewest" => Goal::FewestTrees,
+            "most" => Goal::MostTrees,
+            _ => return Err(invalid()),
+        };
+        match (max_right.trim().parse::<usize>(), max_down.trim().parse::<usize>()) {
+            (Ok(max_right), Ok(max_down)) if max_down > 0 => Ok(Search { goal, max_right, max_down }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// tries every slope with 0..=max_right and 1..=max_down; ties go to the smallest slope. moving right by a whole
+// map width or down past the bottom row hits the same trees as a smaller slope, so the bounds stop there
+fn search_slopes(grid: &Grid, max_right: usize, max_down: usize, goal: Goal) -> Option<(Slope, i64)> {
+    let width = grid.first().map_or(0, |row| row.len());
+    let max_right = max_right.min(width.saturating_sub(1));
+    let max_down = max_down.min(grid.len());
+
+    let mut results = (1..=max_down)
+        .flat_map(|down| (0..=max_right).map(move |right| Slope { right, down }))
+        .map(|slope| (slope, traverse_slope(grid, slope.right, slope.down)));
+    let first = results.next()?;
+
+    Some(results.fold(first, |best, (slope, trees)| {
+        let better = match goal {
+            Goal::FewestTrees => trees < best.1,
+            Goal::MostTrees => trees > best.1,
+        };
+
+        match better || (trees == best.1 && slope < best.0) {
+            true => (slope, trees),
+            false => best,
+        }
+    }))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+enum SquareType {
+    OPEN,
+    TREE,
+}
+
+type Grid = Vec<Vec<SquareType>>;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+enum GridError {
+    Empty,
+    Ragged { line: usize, width: usize, expected: usize },
+    InvalidCharacter { line: usize, column: usize, character: char },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the map has no squares"),
+            GridError::Ragged { line, width, expected } => {
+                write!(f, "line {} is {} squares wide, expected {}", line, width, expected)
+            }
+            GridError::InvalidCharacter { line, column, character } => {
+                write!(f, "line {}, column {}: {:?} is neither '.' nor '#'", line, column, character)
+            }
+        }
+    }
+}
+
+// every row must be as wide as the first, since the map repeats to the right
+fn create_grid(lines: &[String]) -> Result<Grid, GridError> {
+    let grid_width = lines.first().map_or(0, |line| line.chars().count());
+    if grid_width == 0 {
+        return Err(GridError::Empty);
+    }
+
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let row = line.chars()
+                .enumerate()
+                .map(|(j, c)| match c {
+                    '.' => Ok(SquareType::OPEN),
+                    '#' => Ok(SquareType::TREE),
+                    character => Err(GridError::InvalidCharacter { line: i + 1, column: j + 1, character }),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match row.len() == grid_width {
+                true => Ok(row),
+                false => Err(GridError::Ragged { line: i + 1, width: row.len(), expected: grid_width }),
+            }
+        })
+        .collect()
+}
+
+// trees hit on each slope, in the order given
+fn traverse_grid(grid: &Grid, slopes: &[Slope]) -> Vec<i64> {
+    slopes.iter()
+        .map(|slope| traverse_slope(grid, slope.right, slope.down))
+        .collect()
+}
+
+fn traverse_slope(grid: &Grid, dx: usize, dy: usize) -> i64 {
+    // the toboggan starts on the top-left square rather than landing on it
+    let trees_encountered = path(grid, Slope { right: dx, down: dy })
+        .skip(1)
+        .filter(|visit| visit.square == SquareType::TREE)
+        .count() as i64;
+
+    debug!(dx, dy, trees_encountered, "traversed slope");
+
+    trees_encountered
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Visit {
+    row: usize,
+    // the column within the map, which repeats to the right, so it wraps instead of growing with each step
+    column: usize,
+    square: SquareType,
+}
+
+// every square visited, from the top-left start down to the bottom row
+fn path(grid: &Grid, slope: Slope) -> impl Iterator<Item = Visit> + '_ {
+    let width = grid.first().map_or(0, |row| row.len()).max(1);
+
+    iter::successors(Some((0, 0)), move |&(row, column): &(usize, usize)| {
+        Some((row.checked_add(slope.down)?, (column + slope.right % width) % width))
+    })
+    .take_while(move |&(row, _)| row < grid.len())
+    .map(move |(row, column)| Visit { row, column, square: grid[row][column] })
+}
+
+// wider than this, a drawing is no use on a terminal and may not fit in memory
+const MAX_RENDER_COLUMNS: usize = 10_000;
+
+// the map repeated far enough right to hold the whole path, with O and X where it lands on open squares and trees.
+// the start is drawn as it is, since like traverse_slope it isn't counted as a hit
+fn render(grid: &Grid, slope: Slope) -> Result<String, String> {
+    let width = grid.first().map_or(0, |row| row.len());
+    let steps = grid.len().saturating_sub(1) / slope.down;
+    let furthest = steps.checked_mul(slope.right)
+        .filter(|&column| column < MAX_RENDER_COLUMNS)
+        .ok_or_else(|| {
+            format!("right {}, down {} is too wide to draw in {} columns", slope.right, slope.down, MAX_RENDER_COLUMNS)
+        })?;
+    let columns = width * (furthest / width.max(1) + 1);
+
+    let mut landed = vec![None; grid.len()];
+    for (step, visit) in path(grid, slope).enumerate().skip(1) {
+        landed[visit.row] = Some(step * slope.right);
+    }
+
+    Ok(grid.iter()
+        .zip(&landed)
+        .map(|(row, &landed)| {
+            (0..columns)
+                .map(|column| match (row[column % width], landed == Some(column)) {
+                    (SquareType::OPEN, true) => 'O',
+                    (SquareType::TREE, true) => 'X',
+                    (SquareType::OPEN, false) => '.',
+                    (SquareType::TREE, false) => '#',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+
+        assert_eq!(solve_part1(&lines, PART1_SLOPES), Answer::from(7));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+
+        assert_eq!(solve_part2(&lines, PART2_SLOPES), Answer::from(336));
+    }
+
+    #[test]
+    fn test_slopes() {
+        let lines = vec_of_strings![
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+        let grid = create_grid(&lines).unwrap();
+
+        assert_eq!(traverse_grid(&grid, PART2_SLOPES), vec![2, 7, 3, 4, 2]);
+        assert_eq!(solve(&lines, &"3,1 1,2".parse::<Slopes>().unwrap().0), Answer::from(14));
+        assert_eq!("3,0".parse::<Slopes>(), Err("Invalid slope \"3,0\", expected \"<right>,<down>\"".to_string()));
+        assert_eq!("1,1  2, 3".parse::<Slopes>(), Err("Invalid slope \"2,\", expected \"<right>,<down>\"".to_string()));
+        assert_eq!(" ".parse::<Slopes>(), Err("No slopes given".to_string()));
+
+        let all = (1..=2)
+            .flat_map(|down| (0..=7).map(move |right| Slope { right, down }))
+            .collect::<Vec<_>>();
+        let trees = traverse_grid(&grid, &all);
+        let fewest = search_slopes(&grid, 7, 2, Goal::FewestTrees).unwrap();
+        let most = search_slopes(&grid, 7, 2, Goal::MostTrees).unwrap();
+
+        assert_eq!(fewest.1, *trees.iter().min().unwrap());
+        assert_eq!(most.1, *trees.iter().max().unwrap());
+        assert_eq!(most, (Slope { right: 3, down: 1 }, 7));
+        assert_eq!(search_slopes(&grid, 7, 0, Goal::MostTrees), None);
+
+        // bounds far past the map find the same slopes as bounds of one width and one height
+        for &goal in &[Goal::FewestTrees, Goal::MostTrees] {
+            assert_eq!(search_slopes(&grid, usize::MAX, usize::MAX, goal), search_slopes(&grid, 10, 11, goal));
+        }
+        assert_eq!(search_slopes(&grid, 1_000_000, 1_000_000, Goal::FewestTrees), Some((Slope { right: 0, down: 5 }, 0)));
+    }
+
+    #[test]
+    fn test_run_settings() {
+        let lines = vec_of_strings![
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+        let slopes = Runner::with_args("day03", vec_of_strings![
+            "-q",
+            "--set", "part1-slopes=1,1",
+            "--set", "part2-slopes=3,1 1,2",
+        ]);
+        let search = Runner::with_args("day03", vec_of_strings!["-q", "--set", "search=most:7,2"]);
+
+        assert_eq!(run_on(&slopes, &lines), vec![Answer::from(2), Answer::from(14)]);
+        assert_eq!(run_on(&search, &lines), vec![Answer::Text("right 3, down 1 hits 7 trees".to_string())]);
+        assert_eq!("fewest:7,2".parse(), Ok(Search { goal: Goal::FewestTrees, max_right: 7, max_down: 2 }));
+        assert!("least:7,2".parse::<Search>().is_err());
+        assert!("most:7,0".parse::<Search>().is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let lines = vec_of_strings![
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+        let grid = create_grid(&lines).unwrap();
+        let visits = path(&grid, Slope { right: 3, down: 1 }).collect::<Vec<_>>();
+
+        assert_eq!(visits.len(), 11);
+        assert_eq!(visits[0], Visit { row: 0, column: 0, square: SquareType::OPEN });
+        assert_eq!(visits[1], Visit { row: 1, column: 3, square: SquareType::OPEN });
+        assert_eq!(visits[4], Visit { row: 4, column: 1, square: SquareType::TREE });
+        assert_eq!(visits.iter().filter(|v| v.square == SquareType::TREE).count(), 7);
+        assert_eq!(path(&grid, Slope { right: 1, down: 2 }).map(|v| v.row).collect::<Vec<_>>(), vec![0, 2, 4, 6, 8, 10]);
+
+        let rendered = render(&grid, Slope { right: 3, down: 1 }).unwrap();
+        let rendered = rendered.lines().collect::<Vec<_>>();
+
+        assert_eq!(rendered.len(), 11);
+        assert_eq!(rendered[0], "..##.........##.........##.......");
+        assert_eq!(rendered[1], "#..O#...#..#...#...#..#...#...#..");
+        assert_eq!(rendered[2], ".#....X..#..#....#..#..#....#..#.");
+        assert_eq!(rendered[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+
+        // moving right by more than usize can count still wraps around the map, but is too wide to draw
+        let huge = Slope { right: usize::MAX, down: 1 };
+        let wrapped = Slope { right: usize::MAX % 11, down: 1 };
+
+        assert_eq!(path(&grid, huge).collect::<Vec<_>>(), path(&grid, wrapped).collect::<Vec<_>>());
+        assert_eq!(solve_part1(&lines, &[huge]), solve_part1(&lines, &[wrapped]));
+        assert_eq!(render(&grid, huge), Err(format!("right {}, down 1 is too wide to draw in 10000 columns", usize::MAX)));
+        assert_eq!(
+            render(&grid, Slope { right: 1_000_000, down: 1 }),
+            Err("right 1000000, down 1 is too wide to draw in 10000 columns".to_string())
+        );
+
+        // the start isn't counted as a hit, even where it's a tree
+        let tree_start = vec_of_strings!["#.", ".#"];
+        let runner = Runner::with_args("day03", vec_of_strings!["-q", "--set", "render=1,1"]);
+
+        assert_eq!(traverse_slope(&create_grid(&tree_start).unwrap(), 1, 1), 1);
+        assert_eq!(run_on(&runner, &tree_start), vec![Answer::Text("#.\n.X".to_string())]);
+    }
+
+    #[test]
+    fn test_create_grid_errors() {
+        let blank = vec_of_strings![""];
+        let short_row = vec_of_strings!["..#", "#.", "..."];
+        let long_row = vec_of_strings!["..#", "...."];
+        let unknown = vec_of_strings!["..#", ".X."];
+        let valid = vec_of_strings!["#.", ".#"];
+
+        assert_eq!(create_grid(&[]), Err(GridError::Empty));
+        assert_eq!(create_grid(&blank), Err(GridError::Empty));
+        assert_eq!(create_grid(&short_row), Err(GridError::Ragged { line: 2, width: 2, expected: 3 }));
+        assert_eq!(create_grid(&long_row), Err(GridError::Ragged { line: 2, width: 4, expected: 3 }));
+        assert_eq!(create_grid(&unknown), Err(GridError::InvalidCharacter { line: 2, column: 2, character: 'X' }));
+        assert_eq!(solve_part1(&short_row, PART1_SLOPES), Answer::none("line 2 is 2 squares wide, expected 3"));
+        assert_eq!(create_grid(&valid), Ok(vec![
+            vec![SquareType::TREE, SquareType::OPEN],
+            vec![SquareType::OPEN, SquareType::TREE],
+        ]));
+    }
+}
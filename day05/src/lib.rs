@@ -0,0 +1,109 @@
+use common::answer::Answer;
+use common::explain;
+use common::runner::Runner;
+use tracing::debug_span;
+
+const ROW_LOWER_BOUND: u32 = 0;
+const ROW_UPPER_BOUND: u32 = 127;
+const COLUMN_LOWER_BOUND: u32 = 0;
+const COLUMN_UPPER_BOUND: u32 = 7;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_seat_ids(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let seat_ids = debug_span!("parse").in_scope(|| parse_seat_ids(lines));
+
+    seat_ids.into_iter()
+        .max()
+        .map_or_else(|| Answer::none("no boarding passes"), Answer::from)
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let mut seat_ids = debug_span!("parse").in_scope(|| parse_seat_ids(lines));
+
+    seat_ids.sort();
+
+    for (i, seat_id) in seat_ids.iter().enumerate() {
+        if i != 0 && seat_ids[i - 1] != seat_id - 1 {
+            explain!("seat ids jump from {} to {}, so {} is free", seat_ids[i - 1], seat_id, seat_id - 1);
+            return Answer::from(seat_id - 1)
+        }
+    }
+
+    Answer::none("no gap in the seat ids")
+}
+
+fn parse_seat_ids(lines: &[String]) -> Vec<u32> {
+    lines.iter()
+        .map(|l| decode_line(l))
+        .collect()
+}
+
+fn decode_line(line: &String) -> u32 {
+    let mut row_low = ROW_LOWER_BOUND;
+    let mut row_high = ROW_UPPER_BOUND;
+    let mut col_low = COLUMN_LOWER_BOUND;
+    let mut col_high = COLUMN_UPPER_BOUND;
+
+    for c in line.chars() {
+        match c {
+            'F' => row_high = row_high - ((row_high - row_low) + 1) / 2,
+            'B' => row_low = row_low + ((row_high - row_low) + 1) / 2,
+            'L' => col_high = col_high - ((col_high - col_low) + 1) / 2,
+            'R' => col_low = col_low + ((col_high - col_low) + 1) / 2,
+            x => panic!("Invalid character: {}", x)
+        };
+    }
+
+    explain!("{}: row {}, column {}, seat id {}", line, row_low, col_low, (row_low * 8) + col_low);
+
+    (row_low * 8) + col_low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = vec_of_strings![
+            "FBFBBFFRLR",
+            "BFFFBBFRRR",
+            "FFFBBBFRRR",
+            "BBFFBBFRLL",
+        ];
+
+        assert_eq!(solve_part1(&input), Answer::from(820));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = vec_of_strings![
+            "FFFFFFFLLL", // 0
+            "FFFFFFFLLR", // 1
+            "FFFFFFFLRL", // 2
+            "FFFFFFFRLL", // 4
+        ];
+
+        assert_eq!(solve_part2(&input), Answer::from(3));
+    }
+
+    #[test]
+    fn test_decode_line() {
+        assert_eq!(decode_line(&"FBFBBFFRLR".to_string()), 357);
+        assert_eq!(decode_line(&"BFFFBBFRRR".to_string()), 567);
+        assert_eq!(decode_line(&"FFFBBBFRRR".to_string()), 119);
+        assert_eq!(decode_line(&"BBFFBBFRLL".to_string()), 820);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use std::fs;
+use std::process::Command;
+
+// --minimize goes through every day's run, not just day19's
+#[test]
+fn test_minimize_panic() {
+    let dir = std::env::temp_dir().join(format!("day05-minimize-{}", std::process::id()));
+    fs::create_dir_all(dir.join("input")).unwrap();
+    fs::write(dir.join("input/day05.txt"), "FBFBBFFRLR\nBFFFBBFRRR\nFFFBBBXRRR\nBBFFBBFRLL\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_day05"))
+        .args(["--minimize", "panic", "-q"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    let reproducer = fs::read_to_string(dir.join("input/day05.min.txt"));
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(reproducer.unwrap().lines().collect::<Vec<_>>(), vec!["FFFBBBXRRR"]);
+}
@@ -0,0 +1,132 @@
+use common::answer::Answer;
+use common::bitset::FixedBitSet;
+use common::runner::Runner;
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::debug_span;
+
+lazy_static! {
+    static ref GROUP_SEPARATOR: Regex = Regex::new(r"\n\n|\r\n\r\n").unwrap();
+}
+
+pub fn run(runner: &Runner) {
+    let input = runner.input_string();
+
+    runner.dump_input(|| {
+        parse_groups(&input).into_iter()
+            .map(|group| group.lines().collect::<Vec<&str>>())
+            .collect::<Vec<_>>()
+    });
+    runner.minimize_input(|lines, _| {
+        let input = lines.join("\n");
+        solve_part1(&input);
+        Ok(solve_part2(&input))
+    });
+    runner.part(1, || solve_part1(&input));
+    runner.part(2, || solve_part2(&input));
+}
+
+fn parse_groups(input: &str) -> Vec<&str> {
+    GROUP_SEPARATOR.split(input.trim()).collect()
+}
+
+fn solve_part1(input: &String) -> Answer {
+    let groups = debug_span!("parse").in_scope(|| parse_groups(input));
+
+    groups.into_iter()
+        .map(|group| {
+            let people = group.lines().map(answers).collect::<Result<Vec<_>, _>>()?;
+
+            Ok(people.iter().fold(FixedBitSet::new(), |acc, person| acc.union(person)).len())
+        })
+        .sum::<Result<usize, String>>()
+        .map_or_else(Answer::none, Answer::from)
+}
+
+fn solve_part2(input: &String) -> Answer {
+    let groups = debug_span!("parse").in_scope(|| parse_groups(input));
+
+    groups.into_iter()
+        .map(|group| {
+            let people = group.lines().map(answers).collect::<Result<Vec<_>, _>>()?;
+
+            Ok(people.into_iter().reduce(|acc, person| acc.intersection(&person)).map_or(0, |everyone| everyone.len()))
+        })
+        .sum::<Result<usize, String>>()
+        .map_or_else(Answer::none, Answer::from)
+}
+
+// questions are a-z, so one word holds a person's answers; trimming also drops a CRLF file's '\r'
+fn answers(person: &str) -> Result<FixedBitSet<1>, String> {
+    let person = person.trim();
+
+    person.chars()
+        .map(|c| match c {
+            'a'..='z' => Ok(c as usize - 'a' as usize),
+            c => Err(format!("{:?} isn't one of the questions a-z (in {:?})", c, person)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = r#"
+            abc
+
+            a
+            b
+            c
+
+            ab
+            ac
+
+            a
+            a
+            a
+            a
+
+            b
+        "#.to_string();
+
+        assert_eq!(solve_part1(&input), Answer::from(11));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = r#"
+            abc
+
+            a
+            b
+            c
+
+            ab
+            ac
+
+            a
+            a
+            a
+            a
+
+            b
+        "#.to_string();
+
+        assert_eq!(solve_part2(&input), Answer::from(6));
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        let crlf = "abc\r\n\r\na\r\nb\r\nc\r\n\r\nab\r\nac\r\n".to_string();
+        let uppercase = "abc\n\naBc\n".to_string();
+        let digits = "abc\n\n1\n".to_string();
+
+        assert_eq!(solve_part1(&crlf), Answer::from(9));
+        assert_eq!(solve_part2(&crlf), Answer::from(4));
+        assert_eq!(solve_part1(&uppercase), Answer::none("'B' isn't one of the questions a-z (in \"aBc\")"));
+        assert_eq!(solve_part2(&digits), Answer::none("'1' isn't one of the questions a-z (in \"1\")"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use common::answer::Answer;
+use common::graph::Graph;
+use common::runner::Runner;
+use std::collections::{BTreeMap, HashMap};
+use regex::Regex;
+use tracing::{debug, debug_span};
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| build_map(&lines).into_iter().collect::<BTreeMap<_, _>>());
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec(), "shiny gold");
+        Ok(solve_part2(&lines.to_vec(), "shiny gold"))
+    });
+    runner.part(1, || solve_part1(&lines, "shiny gold"));
+    runner.part(2, || solve_part2(&lines, "shiny gold"));
+}
+
+fn solve_part1(lines: &Vec<String>, target_color: &str) -> Answer {
+    let graph = debug_span!("parse").in_scope(|| build_graph(&build_map(lines)));
+
+    // every bag the target is reachable from, walking the containment edges backwards
+    graph.reversed()
+        .bfs(&target_color.to_string())
+        .len()
+        .saturating_sub(1)
+        .into()
+}
+
+fn solve_part2(lines: &Vec<String>, target_color: &str) -> Answer {
+    let graph = debug_span!("parse").in_scope(|| build_graph(&build_map(lines)));
+
+    if !graph.contains(&target_color.to_string()) {
+        return Answer::none(format!("no rule for {} bags", target_color));
+    }
+
+    graph.weighted_sum(&target_color.to_string())
+        .map_or_else(|e| Answer::none(format!("bag rules: {}", e)), |total| Answer::from(total - 1))
+}
+
+fn build_graph(map: &HashMap<String, Vec<(String, i32)>>) -> Graph<String> {
+    let mut graph = Graph::new();
+
+    for (color, contains) in map {
+        graph.add_node(color.clone());
+
+        for (inner_color, quantity) in contains {
+            graph.add_edge(color.clone(), inner_color.clone(), *quantity as i64);
+        }
+    }
+
+    graph
+}
+
+fn build_map(lines: &Vec<String>) -> HashMap<String, Vec<(String, i32)>> {
+    let mut map: HashMap<String, Vec<(String, i32)>> = HashMap::new();
+
+    for l in lines {
+        let bag_color = Regex::new(r"^(.+) bags contain").unwrap()
+            .captures(l).unwrap()[1].to_string();
+        let contains = Regex::new("([0-9]+) ([a-z\\s]+) bag").unwrap()
+            .captures_iter(l)
+            .map(|caps| {
+                (caps[2].to_string(), caps[1].parse::<i32>().unwrap())
+            })
+            .collect();
+
+        map.insert(bag_color, contains);
+    }
+
+    debug!(bags = map.len(), "built bag map");
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = vec_of_strings![
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.",
+            "dark orange bags contain 3 bright white bags, 4 muted yellow bags.",
+            "bright white bags contain 1 shiny gold bag.",
+            "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.",
+            "shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.",
+            "dark olive bags contain 3 faded blue bags, 4 dotted black bags.",
+            "vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.",
+            "faded blue bags contain no other bags.",
+            "dotted black bags contain no other bags.",
+        ];
+
+        assert_eq!(solve_part1(&input, "shiny gold"), Answer::from(4));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = vec_of_strings![
+            "shiny gold bags contain 2 dark red bags.",
+            "dark red bags contain 2 dark orange bags.",
+            "dark orange bags contain 2 dark yellow bags.",
+            "dark yellow bags contain 2 dark green bags.",
+            "dark green bags contain 2 dark blue bags.",
+            "dark blue bags contain 2 dark violet bags.",
+            "dark violet bags contain no other bags.",
+        ];
+
+        assert_eq!(solve_part2(&input, "shiny gold"), Answer::from(126));
+    }
+}
+
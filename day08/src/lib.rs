@@ -0,0 +1,236 @@
+use std::fmt;
+use std::str::FromStr;
+use common::answer::Answer;
+use common::bitset::BitSet;
+use common::budget::{Budget, Exhausted};
+use common::runner::Runner;
+use serde::Serialize;
+use tracing::{debug, debug_span, info};
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_instructions(&lines));
+    runner.minimize_input(|lines, budget| {
+        solve_part1(&lines.to_vec());
+        solve_part2(&lines.to_vec(), budget)
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.budgeted_part(2, |budget| solve_part2(&lines, budget));
+}
+
+fn parse_instructions(lines: &[String]) -> Vec<Instruction> {
+    lines.iter()
+        .map(|l| Instruction::from_str(l).unwrap())
+        .collect()
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let instructions = debug_span!("parse").in_scope(|| parse_instructions(lines));
+
+    let mut executed_instructions = BitSet::with_capacity(instructions.len());
+    let mut pc = 0;
+    let mut acc = 0;
+
+    loop {
+        let i = instructions[pc as usize];
+
+        executed_instructions.insert(pc as usize);
+
+        match i.operation {
+            Operation::ACC => {
+                acc += i.argument;
+                pc += 1;
+            },
+            Operation::JMP => pc += i.argument,
+            Operation::NOP => pc += 1,
+        }
+
+        if executed_instructions.contains(pc as usize) {
+            info!(pc, acc, "halted on repeated instruction");
+            return acc.into();
+        }
+    }
+}
+
+fn solve_part2(lines: &Vec<String>, budget: &Budget) -> Result<Answer, Exhausted> {
+    let original_instructions = debug_span!("parse").in_scope(|| parse_instructions(lines));
+
+    for instructions in permutate_instructions(original_instructions) {
+        let mut executed_instructions = BitSet::with_capacity(instructions.len());
+        let mut pc = 0;
+        let mut acc = 0;
+
+        let val = loop {
+            budget.tick()?;
+
+            let i = instructions[pc as usize];
+
+            executed_instructions.insert(pc as usize);
+
+            match i.operation {
+                Operation::ACC => {
+                    acc += i.argument;
+                    pc += 1;
+                },
+                Operation::JMP => pc += i.argument,
+                Operation::NOP => pc += 1,
+            }
+
+            if pc as usize == instructions.len() {
+                info!(num_executed = executed_instructions.len(), acc, "halted on program end");
+                break Some(acc);
+            } else if executed_instructions.contains(pc as usize) {
+                debug!(pc, acc, "halted on repeated instruction");
+                break None;
+            }
+        };
+
+        if let Some(acc) = val {
+            return Ok(acc.into());
+        }
+    }
+
+    Ok(Answer::none("no single jmp/nop swap lets the program terminate"))
+}
+
+fn permutate_instructions(instructions: Vec<Instruction>) -> Vec<Vec<Instruction>> {
+    let mut permutations: Vec<Vec<Instruction>> = Vec::new();
+
+    for i in 0..instructions.len() {
+        let mut permutation = instructions.clone();
+
+        permutation[i] = match instructions[i].operation {
+            Operation::ACC => continue,
+            Operation::JMP => Instruction {
+                operation: Operation::NOP,
+                argument: instructions[i].argument,
+            },
+            Operation::NOP => Instruction {
+                operation: Operation::JMP,
+                argument: instructions[i].argument,
+            },
+        };
+
+        permutations.push(permutation);
+    }
+
+    permutations
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+enum Operation {
+    ACC,
+    JMP,
+    NOP,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+struct Instruction {
+    operation: Operation,
+    argument: i32,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::ACC => write!(f, "acc"),
+            Operation::JMP => write!(f, "jmp"),
+            Operation::NOP => write!(f, "nop"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:+}", self.operation, self.argument)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operation, argument) = s.split_once(' ').ok_or(format!("Missing argument: {}", s))?;
+        let operation = match operation {
+            "acc" => Operation::ACC,
+            "jmp" => Operation::JMP,
+            "nop" => Operation::NOP,
+            x => return Err(format!("Invalid operation: {}", x)),
+        };
+        if !argument.starts_with(['+', '-']) {
+            return Err(format!("Invalid sign: {}", argument));
+        }
+        let argument = argument.parse::<i32>().map_err(|e| format!("Invalid argument {}: {}", argument, e))?;
+
+        Ok(Instruction {
+            operation,
+            argument,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = vec_of_strings![
+            "nop +0",
+            "acc +1",
+            "jmp +4",
+            "acc +3",
+            "jmp -3",
+            "acc -99",
+            "acc +1",
+            "jmp -4",
+            "acc +6",
+        ];
+
+        assert_eq!(solve_part1(&input), Answer::from(5));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = vec_of_strings![
+            "nop +0",
+            "acc +1",
+            "jmp +4",
+            "acc +3",
+            "jmp -3",
+            "acc -99",
+            "acc +1",
+            "jmp -4",
+            "acc +6",
+        ];
+
+        assert_eq!(solve_part2(&input, &Budget::unlimited()), Ok(Answer::from(8)));
+        assert_eq!(solve_part2(&input, &Budget::new(None, Some(10))), Err(Exhausted::Steps(10)));
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let operation = prop_oneof![Just(Operation::ACC), Just(Operation::JMP), Just(Operation::NOP)];
+
+        (operation, any::<i32>()).prop_map(|(operation, argument)| Instruction { operation, argument })
+    }
+
+    proptest! {
+        #[test]
+        fn test_instruction_round_trip(i in instruction()) {
+            prop_assert_eq!(Instruction::from_str(&i.to_string()), Ok(i));
+        }
+    }
+
+    #[test]
+    fn test_instruction_from_str_errors() {
+        assert_eq!(Instruction::from_str("acc -2147483648").map(|i| i.argument), Ok(i32::MIN));
+        assert!(Instruction::from_str("acc +2147483648").is_err());
+        assert!(Instruction::from_str("jmp +x").is_err());
+        assert!(Instruction::from_str("jmp 4").is_err());
+        assert!(Instruction::from_str("nop").is_err());
+        assert!(Instruction::from_str("mul +1").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use common::answer::Answer;
+use common::explain;
+use common::runner::Runner;
+use itertools::Itertools;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_nums(&lines));
+    runner.minimize_input(|lines, _| {
+        let nums = parse_nums(lines);
+
+        Ok(match solve_part1(&nums, 25).as_i64() {
+            Some(target) => solve_part2(&nums, target),
+            None => Answer::none("part 1 found no invalid number"),
+        })
+    });
+
+    let nums = debug_span!("parse").in_scope(|| parse_nums(&lines));
+    let part1_result = runner.part(1, || solve_part1(&nums, 25));
+    runner.part(2, || match part1_result.as_i64() {
+        Some(target) => solve_part2(&nums, target),
+        None => Answer::none("part 1 found no invalid number"),
+    });
+}
+
+fn parse_nums(lines: &[String]) -> Vec<i64> {
+    lines.iter()
+        .map(|s| s.parse::<i64>().unwrap())
+        .collect()
+}
+
+fn solve_part1(nums: &Vec<i64>, preamble_length: usize) -> Answer {
+    let mut i = 0;
+    for &num in nums.iter().skip(preamble_length) {
+        let mut has_pair = false;
+
+        for n in  nums.iter().skip(i).take(preamble_length).permutations(2).unique() {
+            if n[0] + n[1] == num {
+                has_pair = true;
+            }
+        }
+
+        if !has_pair {
+            explain!(
+                "{} (index {}) is not the sum of two numbers in its window {:?}",
+                num, i + preamble_length, &nums[i..i + preamble_length]
+            );
+            return num.into();
+        }
+
+        i += 1;
+    }
+
+    Answer::none("every number is the sum of two of its predecessors")
+}
+
+fn solve_part2(nums: &Vec<i64>, target: i64) -> Answer {
+    for (i, _) in nums.iter().enumerate() {
+        let mut sum = 0;
+
+        let mut set: Vec<&i64> = nums.iter().skip(i)
+            .take_while(|&n| {
+                if sum + n <= target {
+                    sum += n;
+
+                    return true;
+                }
+
+                false
+            })
+            .collect();
+
+        if sum == target {
+            explain!("indices {}..={} {:?} sum to {}", i, i + set.len() - 1, set, target);
+            set.sort();
+            explain!("smallest {} + largest {}", set[0], set[set.len() - 1]);
+            return (set[0] + set[set.len() - 1]).into();
+        }
+    }
+
+    Answer::none(format!("no contiguous range sums to {}", target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = vec![
+            35,
+            20,
+            15,
+            25,
+            47,
+            40,
+            62,
+            55,
+            65,
+            95,
+            102,
+            117,
+            150,
+            182,
+            127,
+            219,
+            299,
+            277,
+            309,
+            576,
+        ];
+
+        assert_eq!(solve_part1(&input, 5), Answer::from(127));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = vec![
+            35,
+            20,
+            15,
+            25,
+            47,
+            40,
+            62,
+            55,
+            65,
+            95,
+            102,
+            117,
+            150,
+            182,
+            127,
+            219,
+            299,
+            277,
+            309,
+            576,
+        ];
+
+        assert_eq!(solve_part2(&input, 127), Answer::from(62));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use std::fs;
+use std::process::Command;
+
+// a line that doesn't parse is what --minimize should shrink to, not crash on
+#[test]
+fn test_minimize_panic() {
+    let dir = std::env::temp_dir().join(format!("day09-minimize-{}", std::process::id()));
+    fs::create_dir_all(dir.join("input")).unwrap();
+    fs::write(dir.join("input/day09.txt"), "35\n20\nx\n15\n25\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_day09"))
+        .args(["--minimize", "panic", "-q"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    let reproducer = fs::read_to_string(dir.join("input/day09.min.txt"));
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(reproducer.unwrap().lines().collect::<Vec<_>>(), vec!["x"]);
+}
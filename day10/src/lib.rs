@@ -0,0 +1,206 @@
+use common::answer::Answer;
+use common::arith::{self, num};
+use common::explain;
+use common::graph::Graph;
+use common::runner::Runner;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_joltages(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+fn parse_joltages(lines: &[String]) -> Vec<i64> {
+    lines.iter()
+        .map(|s| s.parse::<i64>().unwrap())
+        .collect()
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let mut joltages = debug_span!("parse").in_scope(|| parse_joltages(lines));
+
+    joltages.sort();
+    joltages.push(joltages[joltages.len() - 1] + 3);
+
+    let mut one_jolt_diffs: Vec<i64> = Vec::new();
+    let mut two_jolt_diffs: Vec<i64> = Vec::new();
+    let mut three_jolt_diffs: Vec<i64> = Vec::new();
+
+    for (i, &j2) in joltages.iter().enumerate() {
+        let mut j1 = 0;
+        if i > 0 {
+            j1 = joltages[i - 1];
+        }
+
+        if j2 - j1 == 1 {
+            one_jolt_diffs.push(j1);
+        } else if j2 - j1 == 2 {
+            two_jolt_diffs.push(j1);
+        } else if j2 - j1 == 3 {
+            three_jolt_diffs.push(j1);
+        }
+    }
+
+    explain!(
+        "joltage differences: 1 jolt x{}, 2 jolts x{}, 3 jolts x{}",
+        one_jolt_diffs.len(), two_jolt_diffs.len(), three_jolt_diffs.len()
+    );
+
+    (one_jolt_diffs.len() as i64 * three_jolt_diffs.len() as i64).into()
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let mut joltages = debug_span!("parse").in_scope(|| parse_joltages(lines));
+    joltages.sort();
+
+    // the graph has one node per joltage, and the outlet is the one at 0
+    if let Some(pair) = joltages.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Answer::none(format!("more than one adapter is rated {} jolts", pair[0]));
+    }
+    if joltages.first() == Some(&0) {
+        return Answer::none("an adapter is rated 0 jolts, the same as the outlet");
+    }
+    joltages.insert(0, 0);
+
+    // an adapter can feed any adapter rated 1-3 jolts above it
+    let mut graph = Graph::new();
+    for (i, &joltage) in joltages.iter().enumerate() {
+        graph.add_node(joltage);
+
+        for &next in joltages[i + 1..].iter().take_while(|&&next| next - joltage <= 3) {
+            graph.add_edge(joltage, next, 1);
+        }
+    }
+
+    graph.count_paths_with(&0, joltages.last().unwrap(), num(0), num(1), |a, b| Ok(arith::add(a, b)?))
+        .map_or_else(|e: Answer| e, Answer::from)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines1 = vec_of_strings![
+            "16",
+            "10",
+            "15",
+            "5",
+            "1",
+            "11",
+            "7",
+            "19",
+            "6",
+            "12",
+            "4",
+        ];
+        let lines2 = vec_of_strings![
+            "28",
+            "33",
+            "18",
+            "42",
+            "31",
+            "14",
+            "46",
+            "20",
+            "48",
+            "47",
+            "24",
+            "23",
+            "49",
+            "45",
+            "19",
+            "38",
+            "39",
+            "11",
+            "1",
+            "32",
+            "25",
+            "35",
+            "8",
+            "17",
+            "7",
+            "9",
+            "4",
+            "2",
+            "34",
+            "10",
+            "3",
+        ];
+
+        assert_eq!(solve_part1(&lines1), Answer::from(35));
+        assert_eq!(solve_part1(&lines2), Answer::from(220));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines1 = vec_of_strings![
+            "16",
+            "10",
+            "15",
+            "5",
+            "1",
+            "11",
+            "7",
+            "19",
+            "6",
+            "12",
+            "4",
+        ];
+        let lines2 = vec_of_strings![
+            "28",
+            "33",
+            "18",
+            "42",
+            "31",
+            "14",
+            "46",
+            "20",
+            "48",
+            "47",
+            "24",
+            "23",
+            "49",
+            "45",
+            "19",
+            "38",
+            "39",
+            "11",
+            "1",
+            "32",
+            "25",
+            "35",
+            "8",
+            "17",
+            "7",
+            "9",
+            "4",
+            "2",
+            "34",
+            "10",
+            "3",
+        ];
+
+        assert_eq!(solve_part2(&lines1), Answer::from(8));
+        assert_eq!(solve_part2(&lines2), Answer::from(19208));
+    }
+
+    #[test]
+    fn test_solve_part2_repeated_joltages() {
+        let duplicate = vec_of_strings!["1", "4", "2", "4"];
+        let outlet = vec_of_strings!["0", "1", "3"];
+
+        assert_eq!(solve_part2(&duplicate), Answer::none("more than one adapter is rated 4 jolts"));
+        assert_eq!(solve_part2(&outlet), Answer::none("an adapter is rated 0 jolts, the same as the outlet"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,198 @@
+use common::answer::Answer;
+use common::budget::{Budget, Exhausted};
+use common::geom::{Point, Point2, Vector2};
+use common::progress;
+use common::runner::Runner;
+use serde::Serialize;
+use tracing::{debug, debug_span};
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| create_grid(&lines));
+    runner.minimize_input(|lines, budget| {
+        solve(&lines.to_vec(), false, budget)?;
+        solve(&lines.to_vec(), true, budget)
+    });
+    runner.budgeted_part(1, |budget| solve(&lines, false, budget));
+    runner.budgeted_part(2, |budget| solve(&lines, true, budget));
+}
+
+fn solve(lines: &Vec<String>, part2: bool, budget: &Budget) -> Result<Answer, Exhausted> {
+    let mut grid = debug_span!("parse").in_scope(|| create_grid(lines));
+    let mut num_changed = -1;
+    let mut step = 0;
+
+    while num_changed != 0 {
+        budget.tick()?;
+
+        let (new_grid, changed) = match part2 {
+            false => step_grid_part1(&grid),
+            true => step_grid_part2(&grid),
+        };
+        grid = new_grid;
+        num_changed = changed;
+        step += 1;
+
+        debug!(step, num_changed, "stepped grid");
+        progress::step(step);
+    }
+
+    Ok(grid.iter()
+        .flatten()
+        .filter(|&pos| *pos == PositionType::SEAT(true))
+        .count()
+        .into())
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+enum PositionType {
+    FLOOR,
+    SEAT(bool),
+}
+
+type Grid = Vec<Vec<PositionType>>;
+
+fn create_grid(lines: &Vec<String>) -> Grid {
+    let grid_height = lines.len();
+    let grid_width = lines.iter().next().unwrap().len();
+
+    let mut grid = vec![vec![PositionType::FLOOR; grid_width]; grid_height];
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let square_type = match c {
+                '.' => PositionType::FLOOR,
+                'L' => PositionType::SEAT(false),
+                '#' => PositionType::SEAT(true),
+                _ => panic!("Invalid grid character.")
+            };
+
+            grid[y][x] = square_type;
+        }
+    }
+
+    grid
+}
+
+fn step_grid_part1(grid: &Grid) -> (Grid, i32) {
+    let mut new_grid = grid.clone();
+    let mut num_changed = 0;
+
+    for y in 0..grid.len() {
+        for x in 0..grid[0].len() {
+            match grid[y][x] {
+                PositionType::FLOOR => {}
+                PositionType::SEAT(false) => {
+                    if count_adjacent_seats(&grid, Point([x as i64, y as i64])) == 0 {
+                        new_grid[y][x] = PositionType::SEAT(true);
+                        num_changed += 1;
+                    }
+                }
+                PositionType::SEAT(true) => {
+                    if count_adjacent_seats(&grid, Point([x as i64, y as i64])) >= 4 {
+                        new_grid[y][x] = PositionType::SEAT(false);
+                        num_changed += 1
+                    }
+                }
+            };
+        }
+    }
+
+    (new_grid, num_changed)
+}
+
+fn step_grid_part2(grid: &Grid) -> (Grid, i32) {
+    let mut new_grid = grid.clone();
+    let mut num_changed = 0;
+
+    for y in 0..grid.len() {
+        for x in 0..grid[0].len() {
+            match grid[y][x] {
+                PositionType::FLOOR => {}
+                PositionType::SEAT(false) => {
+                    if count_line_of_sight_seats(&grid, Point([x as i64, y as i64])) == 0 {
+                        new_grid[y][x] = PositionType::SEAT(true);
+                        num_changed += 1;
+                    }
+                }
+                PositionType::SEAT(true) => {
+                    if count_line_of_sight_seats(&grid, Point([x as i64, y as i64])) >= 5 {
+                        new_grid[y][x] = PositionType::SEAT(false);
+                        num_changed += 1
+                    }
+                }
+            };
+        }
+    }
+
+    (new_grid, num_changed)
+}
+
+// off-grid coordinates, negative ones included, wrap to huge indices and come back as None
+fn position_at(grid: &Grid, p: Point2) -> Option<&PositionType> {
+    grid.get(p[1] as usize).and_then(|row| row.get(p[0] as usize))
+}
+
+fn count_adjacent_seats(grid: &Grid, p: Point2) -> usize {
+    p.neighbours()
+        .filter(|&n| position_at(grid, n) == Some(&PositionType::SEAT(true)))
+        .count()
+}
+
+fn count_line_of_sight_seats(grid: &Grid, p: Point2) -> usize {
+    Vector2::directions()
+        .filter(|&d| {
+            let mut seen = p + d;
+
+            while position_at(grid, seen) == Some(&PositionType::FLOOR) {
+                seen += d;
+            }
+
+            position_at(grid, seen) == Some(&PositionType::SEAT(true))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ];
+
+        assert_eq!(solve(&lines, false, &Budget::unlimited()), Ok(Answer::from(37)));
+        assert_eq!(solve(&lines, false, &Budget::new(None, Some(3))), Err(Exhausted::Steps(3)));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ];
+
+        assert_eq!(solve(&lines, true, &Budget::unlimited()), Ok(Answer::from(26)));
+    }
+}
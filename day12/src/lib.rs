@@ -0,0 +1,165 @@
+use common::answer::Answer;
+use common::geom::{Point, Vector, Vector2};
+use common::runner::Runner;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_actions(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+fn parse_actions(lines: &[String]) -> Vec<Action> {
+    lines.iter()
+        .map(|l| Action::from_str(l).unwrap())
+        .collect()
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let actions = debug_span!("parse").in_scope(|| parse_actions(lines));
+
+    let mut ship = Point::origin();
+    let mut facing = Vector2::EAST;
+
+    actions.iter()
+        .for_each(|a| {
+            match *a {
+                Action::NORTH(val) => ship += Vector2::NORTH * val,
+                Action::SOUTH(val) => ship += Vector2::SOUTH * val,
+                Action::EAST(val) => ship += Vector2::EAST * val,
+                Action::WEST(val) => ship += Vector2::WEST * val,
+                Action::LEFT(degrees) => facing = facing.turn_left(degrees / 90),
+                Action::RIGHT(degrees) => facing = facing.turn_right(degrees / 90),
+                Action::FORWARD(val) => ship += facing * val,
+            }
+        });
+
+    ship.manhattan_distance(&Point::origin()).into()
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let actions = debug_span!("parse").in_scope(|| parse_actions(lines));
+
+    let mut ship = Point::origin();
+    let mut waypoint = Vector([10, 1]);
+
+    actions.iter()
+        .for_each(|a| {
+            match *a {
+                Action::NORTH(val) => waypoint += Vector2::NORTH * val,
+                Action::SOUTH(val) => waypoint += Vector2::SOUTH * val,
+                Action::EAST(val) => waypoint += Vector2::EAST * val,
+                Action::WEST(val) => waypoint += Vector2::WEST * val,
+                Action::LEFT(degrees) => waypoint = waypoint.turn_left(degrees / 90),
+                Action::RIGHT(degrees) => waypoint = waypoint.turn_right(degrees / 90),
+                Action::FORWARD(val) => ship += waypoint * val,
+            }
+        });
+
+    ship.manhattan_distance(&Point::origin()).into()
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+enum Action {
+    NORTH(i64),
+    EAST(i64),
+    SOUTH(i64),
+    WEST(i64),
+    LEFT(i64),
+    RIGHT(i64),
+    FORWARD(i64),
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::NORTH(val) => write!(f, "N{}", val),
+            Action::EAST(val) => write!(f, "E{}", val),
+            Action::SOUTH(val) => write!(f, "S{}", val),
+            Action::WEST(val) => write!(f, "W{}", val),
+            Action::LEFT(val) => write!(f, "L{}", val),
+            Action::RIGHT(val) => write!(f, "R{}", val),
+            Action::FORWARD(val) => write!(f, "F{}", val),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let val = s[1..].trim().parse::<i64>().unwrap();
+
+        Ok(match &s[..1] {
+            "N" => Action::NORTH(val),
+            "E" => Action::EAST(val),
+            "S" => Action::SOUTH(val),
+            "W" => Action::WEST(val),
+            "L" => Action::LEFT(val),
+            "R" => Action::RIGHT(val),
+            "F" => Action::FORWARD(val),
+            _ => panic!(format!("Invalid action: {}", s)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "F10",
+            "N3",
+            "F7",
+            "R90",
+            "F11",
+        ];
+
+        assert_eq!(solve_part1(&lines), Answer::from(25));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "F10",
+            "N3",
+            "F7",
+            "R90",
+            "F11",
+        ];
+
+        assert_eq!(solve_part2(&lines), Answer::from(286));
+    }
+
+    fn action() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            any::<i64>().prop_map(Action::NORTH),
+            any::<i64>().prop_map(Action::EAST),
+            any::<i64>().prop_map(Action::SOUTH),
+            any::<i64>().prop_map(Action::WEST),
+            any::<i64>().prop_map(Action::LEFT),
+            any::<i64>().prop_map(Action::RIGHT),
+            any::<i64>().prop_map(Action::FORWARD),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_action_round_trip(a in action()) {
+            prop_assert_eq!(Action::from_str(&a.to_string()), Ok(a));
+        }
+    }
+}
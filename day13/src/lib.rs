@@ -0,0 +1,172 @@
+use common::answer::Answer;
+use common::arith::{self, num, Num, Overflow};
+use common::runner::Runner;
+use serde::Serialize;
+use std::fmt;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_schedule(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Schedule {
+    arrival_timestamp: i64,
+    bus_ids: Vec<Option<i64>>,
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bus_ids = self.bus_ids.iter()
+            .map(|id| id.map_or("x".to_string(), |id| id.to_string()))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}\n{}", self.arrival_timestamp, bus_ids.join(","))
+    }
+}
+
+fn parse_schedule(lines: &[String]) -> Schedule {
+    let arrival_timestamp = lines[0].parse::<i64>().unwrap();
+    let bus_ids = lines[1].split(",")
+        .map(|s| match s {
+            "x" => None,
+            s => Some(s.parse::<i64>().unwrap()),
+        })
+        .collect();
+
+    Schedule {
+        arrival_timestamp,
+        bus_ids,
+    }
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let schedule = debug_span!("parse").in_scope(|| parse_schedule(lines));
+    let arrival_timestamp = schedule.arrival_timestamp;
+
+    let mut closest_bus_id = -1;
+    let mut closest = i64::MAX;
+    for bus_id in schedule.bus_ids.into_iter().flatten() {
+        if bus_id - (arrival_timestamp % bus_id) < closest {
+            closest_bus_id = bus_id;
+            closest = bus_id - (arrival_timestamp % bus_id);
+        }
+    }
+
+    (closest * closest_bus_id).into()
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let schedule = debug_span!("parse").in_scope(|| parse_schedule(lines));
+    let mut modulii = Vec::new();
+    let mut residues = Vec::new();
+
+    for (i, bus_id) in schedule.bus_ids.iter().enumerate() {
+        if let Some(val) = bus_id {
+            modulii.push(num(*val));
+            residues.push(num(val - i as i64));
+        }
+    }
+
+    match chinese_remainder(&residues, &modulii) {
+        Ok(Some(timestamp)) => timestamp.into(),
+        Ok(None) => Answer::none("bus ids are not pairwise coprime"),
+        Err(e) => e.into(),
+    }
+}
+
+// from: https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
+// operands are borrowed throughout because Num may be a BigInt
+#[allow(clippy::op_ref)]
+fn egcd(a: Num, b: Num) -> (Num, Num, Num) {
+    if a == num(0) {
+        (b, num(0), num(1))
+    } else {
+        let q = &b / &a;
+        let (g, x, y) = egcd(b % &a, a);
+        (g, y - q * &x, x)
+    }
+}
+
+#[allow(clippy::op_ref)]
+fn mod_inv(x: Num, n: &Num) -> Option<Num> {
+    let (g, x, _) = egcd(x, n.to_owned());
+    if g == num(1) {
+        Some((x % n + n) % n)
+    } else {
+        None
+    }
+}
+
+// the modulus product is the first thing to outgrow an i64
+#[allow(clippy::op_ref)]
+fn chinese_remainder(residues: &[Num], modulii: &[Num]) -> Result<Option<Num>, Overflow> {
+    let prod = arith::product(modulii.to_vec())?;
+
+    let mut sum = num(0);
+
+    for (residue, modulus) in residues.iter().cloned().zip(modulii) {
+        let p = &prod / modulus;
+        let inv = match mod_inv(p.to_owned(), modulus) {
+            Some(inv) => inv,
+            None => return Ok(None),
+        };
+
+        sum = arith::add(sum, arith::mul(arith::mul(residue, inv)?, p)?)?;
+    }
+
+    Ok(Some(sum % &prod))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_solve_part1() {
+        assert_eq!(solve_part1(&vec_of_strings!["939", "7,13,x,x,59,x,31,19"]), Answer::from(295));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        assert_eq!(solve_part2(&vec_of_strings!["939", "17,x,13,19"]), Answer::from(3417));
+        assert_eq!(solve_part2(&vec_of_strings!["939", "67,7,59,61"]), Answer::from(754018));
+        assert_eq!(solve_part2(&vec_of_strings!["939", "67,x,7,59,61"]), Answer::from(779210));
+        assert_eq!(solve_part2(&vec_of_strings!["939", "67,7,x,59,61"]), Answer::from(1261476));
+        assert_eq!(solve_part2(&vec_of_strings!["939", "1789,37,47,1889"]), Answer::from(1202161486));
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let lines = vec_of_strings!["939", "17,x,13"];
+
+        assert_eq!(parse_schedule(&lines), Schedule {
+            arrival_timestamp: 939,
+            bus_ids: vec![Some(17), None, Some(13)],
+        });
+    }
+
+    fn schedule() -> impl Strategy<Value = Schedule> {
+        (any::<i64>(), prop::collection::vec(prop::option::of(any::<i64>()), 1..20))
+            .prop_map(|(arrival_timestamp, bus_ids)| Schedule { arrival_timestamp, bus_ids })
+    }
+
+    proptest! {
+        #[test]
+        fn test_schedule_round_trip(s in schedule()) {
+            let lines = s.to_string().lines().map(String::from).collect::<Vec<_>>();
+
+            prop_assert_eq!(parse_schedule(&lines), s);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use common::answer::Answer;
+use common::budget::{Budget, Exhausted, CLOCK_CHECK_INTERVAL};
+use common::progress;
+use common::runner::Runner;
+use std::collections::HashMap;
+
+pub fn run(runner: &Runner) {
+    let starting_numbers = vec![11, 0, 1, 10, 5, 19];
+
+    runner.dump_input(|| &starting_numbers);
+    runner.reject_minimize("its starting numbers are built in");
+    runner.budgeted_part(1, |budget| solve(starting_numbers.clone(), 2020, budget));
+    runner.budgeted_part(2, |budget| solve(starting_numbers.clone(), 30000000, budget));
+}
+
+fn solve(starting_numbers: Vec<i32>, num_turns: i32, budget: &Budget) -> Result<Answer, Exhausted> {
+    let mut seen: HashMap<i32, i32> = HashMap::new();
+    let mut seen2: HashMap<i32, i32> = HashMap::new();
+    let mut last = starting_numbers.last().unwrap().clone();
+
+    for (i, num) in starting_numbers.iter().enumerate() {
+        seen.insert(num.clone(), i as i32 + 1);
+    }
+
+    for turn_number in starting_numbers.len() as i32 + 1..=num_turns {
+        budget.tick_every(CLOCK_CHECK_INTERVAL)?;
+        progress::report(turn_number as u64, num_turns as u64);
+
+        let mut next = 0;
+        if seen.contains_key(&last) {
+            let last_turn_number_seen = seen.get(&last).unwrap();
+
+            if seen2.contains_key(&last) {
+                next = last_turn_number_seen - seen2.get(&last).unwrap();
+            }
+        }
+
+        if seen.contains_key(&next) {
+            seen2.insert(next, seen.get(&next).unwrap().clone());
+        }
+        seen.insert(next, turn_number);
+
+        last = next.clone();
+    }
+
+    Ok(last.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(vec![0, 3, 6], 10, &Budget::unlimited()), Ok(Answer::from(0)));
+        assert_eq!(solve(vec![0, 3, 6], 2020, &Budget::unlimited()), Ok(Answer::from(436)));
+        assert_eq!(solve(vec![0, 3, 6], 2020, &Budget::new(None, Some(100))), Err(Exhausted::Steps(100)));
+    }
+}
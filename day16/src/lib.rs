@@ -0,0 +1,191 @@
+use common::answer::Answer;
+use common::bitset::BitSet;
+use common::explain;
+use common::intervals::{IntervalIndex, IntervalSet};
+use common::runner::Runner;
+use serde::Serialize;
+use tracing::{debug, debug_span};
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_notes(&lines));
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+type Rule = IntervalSet<usize>;
+type Ticket = Vec<usize>;
+
+fn rule_from_str(s: &str) -> Rule {
+    let split: Vec<&str> = s.split(": ").collect();
+
+    split[1].split(" or ")
+        .map(|s| {
+            let nums = s.split("-")
+                .map(|s| s.parse::<usize>().unwrap())
+                .collect::<Vec<_>>();
+
+            nums[0]..=nums[1]
+        })
+        .collect()
+}
+
+fn ticket_from_str(s: &str) -> Ticket {
+    s.split(",").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<_>>()
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Notes {
+    rules: Vec<Rule>,
+    my_ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+fn parse_notes(lines: &[String]) -> Notes {
+    let rules = lines.iter()
+        .take_while(|&s| s.trim() != "")
+        .map(|s| rule_from_str(s))
+        .collect::<Vec<_>>();
+
+    let my_ticket = lines.iter()
+        .skip_while(|&s| s != "your ticket:")
+        .skip(1)
+        .next()
+        .map(|s| ticket_from_str(s))
+        .unwrap();
+
+    let nearby_tickets = lines.iter()
+        .skip_while(|&s| s != "nearby tickets:")
+        .skip(1)
+        .map(|s| ticket_from_str(s))
+        .collect::<Vec<_>>();
+
+    Notes {
+        rules,
+        my_ticket,
+        nearby_tickets,
+    }
+}
+
+fn any_rule(rules: &[Rule]) -> Rule {
+    rules.iter().fold(IntervalSet::new(), |acc, rule| acc.union(rule))
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let Notes { rules, nearby_tickets, .. } = debug_span!("parse").in_scope(|| parse_notes(lines));
+
+    let any_rule = any_rule(&rules);
+
+    let mut result = 0;
+    nearby_tickets.iter()
+        .for_each(|t| {
+            match t.iter().find(|&&val| !any_rule.contains(val)) {
+                Some(v) => result += v,
+                None => {}
+            }
+        });
+
+    result.into()
+}
+
+fn possible_rules(rules: &Vec<Rule>, tickets: &Vec<Ticket>) -> Vec<BitSet> {
+    let index = IntervalIndex::new(rules);
+
+    (0..rules.len()).map(|i|
+        tickets.iter().fold((0..rules.len()).collect::<BitSet>(), |possible, t| {
+            possible.intersection(&index.covering(t[i]).iter().copied().collect())
+        })
+    ).collect()
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let Notes { rules, my_ticket, nearby_tickets } = debug_span!("parse").in_scope(|| parse_notes(lines));
+
+    let any_rule = any_rule(&rules);
+
+    let mut valid_tickets = Vec::new();
+    valid_tickets.push(my_ticket.clone());
+
+    nearby_tickets.iter()
+        .for_each(|t| {
+            match t.iter().find(|&&val| !any_rule.contains(val)) {
+                Some(_) => {}
+                None => valid_tickets.push(t.clone()),
+            }
+        });
+
+    let mut possible_rules = possible_rules(&rules, &valid_tickets);
+    let mut assigned_rules = [0; 20];
+    while let Some(i) = possible_rules.iter().position(|s| s.len() == 1) {
+        let v = possible_rules[i].iter().next().unwrap();
+        debug!(field = i, rule = v, "assigned rule to field");
+        assigned_rules[i] = v;
+        for s in &mut possible_rules {
+            s.remove(v);
+        }
+    }
+    for (i, &rule) in assigned_rules.iter().enumerate().take(rules.len()) {
+        let name = lines[rule].split(": ").next().unwrap();
+
+        explain!("column {} is {} ({} on my ticket)", i, name, my_ticket[i]);
+    }
+
+    assigned_rules.iter()
+        .enumerate()
+        .filter(|(_,&rule)| rule < 6)
+        .map(|(i,_)| my_ticket[i])
+        .product::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "class: 1-3 or 5-7",
+            "row: 6-11 or 33-44",
+            "seat: 13-40 or 45-50",
+            "",
+            "your ticket:",
+            "7,1,14",
+            "",
+            "nearby tickets:",
+            "7,3,47",
+            "40,4,50",
+            "55,2,20",
+            "38,6,12",
+        ];
+
+        assert_eq!(solve_part1(&lines), Answer::from(71));
+    }
+
+    #[test]
+    fn test_parse_notes() {
+        let lines = vec_of_strings![
+            "class: 1-3 or 5-7",
+            "row: 6-11 or 33-44",
+            "",
+            "your ticket:",
+            "7,1",
+            "",
+            "nearby tickets:",
+            "7,3",
+            "40,4",
+        ];
+
+        assert_eq!(parse_notes(&lines), Notes {
+            rules: vec![vec![1..=3, 5..=7].into_iter().collect(), vec![6..=11, 33..=44].into_iter().collect()],
+            my_ticket: vec![7, 1],
+            nearby_tickets: vec![vec![7, 3], vec![40, 4]],
+        });
+    }
+}
@@ -0,0 +1,115 @@
+use common::answer::Answer;
+use common::geom::Point;
+use common::runner::Runner;
+use std::collections::{BTreeSet, HashSet};
+use tracing::{debug, debug_span};
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| parse_active_coords(&lines).into_iter().collect::<BTreeSet<_>>());
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    let active_coords = debug_span!("parse").in_scope(|| parse_active_coords(lines));
+
+    simulate(active_coords.iter().map(|&(x, y)| Point([x, y, 0])).collect()).into()
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    let active_coords = debug_span!("parse").in_scope(|| parse_active_coords(lines));
+
+    simulate(active_coords.iter().map(|&(x, y)| Point([x, y, 0, 0])).collect()).into()
+}
+
+fn parse_active_coords(lines: &[String]) -> HashSet<(i64, i64)> {
+    let mut active_coords = HashSet::new();
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                active_coords.insert((x as i64, y as i64));
+            }
+        }
+    }
+
+    active_coords
+}
+
+fn get_inactive_adjacents<const D: usize>(active_coords: &HashSet<Point<D>>) -> HashSet<Point<D>> {
+    active_coords.iter()
+        .flat_map(|active| active.neighbours())
+        .filter(|adj| !active_coords.contains(adj))
+        .collect()
+}
+
+fn step<const D: usize>(active_coords: HashSet<Point<D>>) -> HashSet<Point<D>> {
+    let inactive_coords = get_inactive_adjacents(&active_coords);
+    let mut next = HashSet::new();
+
+    for &active in active_coords.iter() {
+        let active_neighbors = active.neighbours()
+            .filter(|x| active_coords.contains(x))
+            .count();
+
+        if active_neighbors == 2 || active_neighbors == 3 {
+            next.insert(active);
+        }
+    }
+
+    for &inactive in inactive_coords.iter() {
+        let active_neighbors = inactive.neighbours()
+            .filter(|x| active_coords.contains(x))
+            .count();
+
+        if active_neighbors == 3 {
+            next.insert(inactive);
+        }
+    }
+
+    next
+}
+
+fn simulate<const D: usize>(mut active_coords: HashSet<Point<D>>) -> usize {
+    for cycle in 1..=6 {
+        active_coords = step(active_coords);
+
+        debug!(cycle, active = active_coords.len(), "simulated cycle");
+    }
+
+    active_coords.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            ".#.",
+            "..#",
+            "###",
+        ];
+
+        assert_eq!(solve_part1(&lines), Answer::from(112));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            ".#.",
+            "..#",
+            "###",
+        ];
+
+        assert_eq!(solve_part2(&lines), Answer::from(848));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use common::answer::Answer;
+use common::arith::{self, num, Num, Overflow};
+use common::runner::Runner;
+use std::collections::HashMap;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| &lines);
+    runner.minimize_input(|lines, _| {
+        solve_part1(&lines.to_vec());
+        Ok(solve_part2(&lines.to_vec()))
+    });
+    runner.part(1, || solve_part1(&lines));
+    runner.part(2, || solve_part2(&lines));
+}
+
+fn solve_part1(lines: &Vec<String>) -> Answer {
+    lines.iter()
+        .map(|s| evaluate(&s, false))
+        .try_fold(num(0), |acc, value| arith::add(acc, value?))
+        .map_or_else(Answer::from, Answer::from)
+}
+
+fn solve_part2(lines: &Vec<String>) -> Answer {
+    lines.iter()
+        .map(|s| evaluate(&s, true))
+        .try_fold(num(0), |acc, value| arith::add(acc, value?))
+        .map_or_else(Answer::from, Answer::from)
+}
+
+fn evaluate(s: &String, part2: bool) -> Result<Num, Overflow> {
+    let mut value_stack = Vec::new();
+    let mut operator_stack = Vec::new();
+
+    let mut precedence_map = HashMap::new();
+    precedence_map.insert('+', 1);
+    precedence_map.insert('*', 2);
+
+    for c in s.chars() {
+        match c {
+            ' ' => {},
+            '(' => operator_stack.push(c),
+            ')' => {
+                while operator_stack.last().is_some() && operator_stack.last().unwrap() != &'(' {
+                    let operator = operator_stack.pop().unwrap();
+                    let operand1 = value_stack.pop().unwrap();
+                    let operand2 = value_stack.pop().unwrap();
+
+                    value_stack.push(apply_operator(operator, operand1, operand2)?);
+                }
+                operator_stack.pop();
+            },
+            '+' | '*' => {
+                while operator_stack.last().is_some() && operator_stack.last().unwrap() != &'(' {
+                    if part2 {
+                        if precedence_map.get(&c).unwrap() < precedence_map.get(operator_stack.last().unwrap()).unwrap() {
+                            break;
+                        }
+                    }
+
+                    let operator = operator_stack.pop().unwrap();
+                    let operand1 = value_stack.pop().unwrap();
+                    let operand2 = value_stack.pop().unwrap();
+
+                    value_stack.push(apply_operator(operator, operand1, operand2)?);
+                }
+                operator_stack.push(c);
+            },
+            x => {
+                value_stack.push(num(x.to_string().parse::<i64>().unwrap()));
+            },
+        };
+    }
+
+    while operator_stack.last().is_some() {
+        let operator = operator_stack.pop().unwrap();
+        let operand1 = value_stack.pop().unwrap();
+        let operand2 = value_stack.pop().unwrap();
+
+        value_stack.push(apply_operator(operator, operand1, operand2)?);
+    }
+
+    Ok(value_stack.pop().unwrap())
+}
+
+fn apply_operator(operator: char, operand1: Num, operand2: Num) -> Result<Num, Overflow> {
+    match operator {
+        '+' => arith::add(operand1, operand2),
+        '*' => arith::mul(operand1, operand2),
+        x => panic!(format!("Invalid operator: {}", x)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+
+    #[test]
+    fn test_part1() {
+        let lines = vec_of_strings![
+            "1 + 2 * 3 + 4 * 5 + 6",
+            "1 + (2 * 3) + (4 * (5 + 6))",
+        ];
+
+        assert_eq!(solve_part1(&lines), Answer::from(122));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(evaluate(&"1".to_string(), false), Ok(num(1)));
+        assert_eq!(evaluate(&"1 + 2 * 3 + 4 * 5 + 6".to_string(), false), Ok(num(71)));
+        assert_eq!(evaluate(&"1 + (2 * 3) + (4 * (5 + 6))".to_string(), false), Ok(num(51)));
+        assert_eq!(evaluate(&"2 * 3 + (4 * 5)".to_string(), false), Ok(num(26)));
+        assert_eq!(evaluate(&"5 + (8 * 3 + 9 + 3 * 4 * 3)".to_string(), false), Ok(num(437)));
+        assert_eq!(evaluate(&"5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))".to_string(), false), Ok(num(12240)));
+        assert_eq!(evaluate(&"((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_string(), false), Ok(num(13632)));
+    }
+
+    #[test]
+    fn test_evaluate_part2() {
+        assert_eq!(evaluate(&"1".to_string(), true), Ok(num(1)));
+        assert_eq!(evaluate(&"1 + 2 * 3 + 4 * 5 + 6".to_string(), true), Ok(num(231)));
+        assert_eq!(evaluate(&"1 + (2 * 3) + (4 * (5 + 6))".to_string(), true), Ok(num(51)));
+        assert_eq!(evaluate(&"2 * 3 + (4 * 5)".to_string(), true), Ok(num(46)));
+        assert_eq!(evaluate(&"5 + (8 * 3 + 9 + 3 * 4 * 3)".to_string(), true), Ok(num(1445)));
+        assert_eq!(evaluate(&"5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))".to_string(), true), Ok(num(669060)));
+        assert_eq!(evaluate(&"((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_string(), true), Ok(num(23340)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,326 @@
+use common::answer::Answer;
+use common::budget::{Budget, Exhausted};
+use common::progress;
+use common::runner::Runner;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use tracing::debug_span;
+
+pub fn run(runner: &Runner) {
+    let lines = runner.input_lines();
+
+    runner.dump_input(|| {
+        let rules = build_rules(&lines);
+        let messages = lines.get(rules.len() + 1..).unwrap_or(&[]).to_vec();
+
+        (rules.into_iter().collect::<BTreeMap<_, _>>(), messages)
+    });
+    runner.minimize_input_against(
+        |lines, budget| solve_part1(&lines.to_vec(), budget),
+        solve_part1_regex,
+    );
+    runner.budgeted_part(1, |budget| solve_part1(&lines, budget));
+    runner.budgeted_part(2, |budget| solve_part2(&lines, budget));
+}
+
+fn solve_part1(lines: &Vec<String>, budget: &Budget) -> Result<Answer, Exhausted> {
+    let rules = debug_span!("parse").in_scope(|| build_rules(&lines));
+
+    let messages = lines.get(rules.len() + 1..).unwrap_or(&[]);
+    let results = messages.iter()
+        .enumerate()
+        .map(|(i, message)| {
+            progress::report(i as u64 + 1, messages.len() as u64);
+
+            matches(message, &rules, 0, budget)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(results.iter()
+        .filter(|results| results.iter().any(|&result| result.is_empty()))
+        .count()
+        .into())
+}
+
+// reference implementation for --minimize mismatch; only works without the looping rules of part 2
+fn solve_part1_regex(lines: &[String]) -> Answer {
+    let rules = build_rules(&lines.to_vec());
+    let re = Regex::new(&format!("^{}$", rule_regex(&rules, 0))).unwrap();
+
+    lines.get(rules.len() + 1..).unwrap_or(&[]).iter()
+        .filter(|message| re.is_match(message))
+        .count()
+        .into()
+}
+
+fn rule_regex(rules: &HashMap<u64, Rule>, rule_number: u64) -> String {
+    let sequence = |rule_numbers: &Vec<u64>| rule_numbers.iter()
+        .map(|&r| rule_regex(rules, r))
+        .collect::<String>();
+
+    match rules.get(&rule_number).unwrap() {
+        Rule::Char(c) => regex::escape(&c.to_string()),
+        Rule::Rules(inner_rules) => sequence(inner_rules),
+        Rule::Or((left, right)) => format!("(?:{}|{})", sequence(left), sequence(right)),
+    }
+}
+
+fn solve_part2(lines: &Vec<String>, budget: &Budget) -> Result<Answer, Exhausted> {
+    let mut rules = debug_span!("parse").in_scope(|| build_rules(&lines));
+
+    rules.insert(8, Rule::Or((vec![42], vec![42, 8])));
+    rules.insert(11, Rule::Or((vec![42, 31], vec![42, 11, 31])));
+
+    let messages = lines.get(rules.len() + 1..).unwrap_or(&[]);
+    let results = messages.iter()
+        .enumerate()
+        .map(|(i, message)| {
+            progress::report(i as u64 + 1, messages.len() as u64);
+
+            matches(message, &rules, 0, budget)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(results.iter()
+        .filter(|results| results.iter().any(|&result| result.is_empty()))
+        .count()
+        .into())
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+enum Rule {
+    Char(char),
+    Rules(Vec<u64>),
+    Or((Vec<u64>, Vec<u64>)),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = |rules: &[u64]| rules.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(" ");
+
+        match self {
+            Rule::Char(c) => write!(f, "\"{}\"", c),
+            Rule::Rules(rules) => write!(f, "{}", join(rules)),
+            Rule::Or((left, right)) => write!(f, "{} | {}", join(left), join(right)),
+        }
+    }
+}
+
+fn build_rules(lines: &Vec<String>) -> HashMap<u64, Rule> {
+    lines.iter()
+        .take_while(|&line| !line.trim().is_empty())
+        .map(|line| {
+            let parts = line.split(": ").collect::<Vec<_>>();
+
+            let rule_number = parts[0].parse::<u64>().unwrap();
+            let rule = match parts[1].trim().starts_with('"') {
+                true => Rule::Char(parts[1].trim().trim_matches('"').chars().next().unwrap()),
+                false => {
+                    if parts[1].contains("|") {
+                        let (left, right) = parts[1].split_once(" | ").unwrap();
+
+                        Rule::Or((
+                            left.split(" ").map(|n| n.parse::<u64>().unwrap()).collect(),
+                            right.split(" ").map(|n| n.parse::<u64>().unwrap()).collect(),
+                        ))
+                    } else {
+                        Rule::Rules(parts[1].split(" ").map(|n| n.parse::<u64>().unwrap()).collect())
+                    }
+                }
+            };
+
+            (rule_number, rule)
+        })
+        .collect::<HashMap<_, _>>()
+}
+
+fn matches<'a>(msg: &'a str, rules: &HashMap<u64, Rule>, rule_number: u64, budget: &Budget) -> Result<Vec<&'a str>, Exhausted> {
+    budget.tick()?;
+
+    Ok(match rules.get(&rule_number).unwrap() {
+        Rule::Char(c) => {
+            match msg.chars().next() {
+                Some(ch) => {
+                    if *c == ch {
+                        vec![&msg[1..]]
+                    } else {
+                        vec![]
+                    }
+                }
+                None => vec![]
+            }
+        }
+        Rule::Rules(inner_rules) => {
+            let mut remaining = vec![msg];
+
+            for &r in inner_rules.iter() {
+                let mut next = vec![];
+                let mut no_match = true;
+
+                for &s in remaining.iter() {
+                    let mut results = matches(s, rules, r, budget)?;
+                    if !results.is_empty() {
+                        no_match = false;
+                    }
+                    next.append(&mut results);
+                }
+                if no_match {
+                    return Ok(vec![]);
+                }
+
+                remaining = next;
+            }
+
+            remaining
+        }
+        Rule::Or((left, right)) => {
+            let mut left_remaining = vec![msg];
+            for &r in left.iter() {
+                let mut next = vec![];
+                let mut no_match = true;
+
+                for &s in left_remaining.iter() {
+                    let mut results = matches(s, rules, r, budget)?;
+                    if !results.is_empty() {
+                        no_match = false;
+                    }
+                    next.append(&mut results);
+                }
+                if no_match {
+                    left_remaining = vec![];
+                    break;
+                }
+
+                left_remaining = next;
+            }
+
+            let mut right_remaining = vec![msg];
+            for &r in right.iter() {
+                let mut next = vec![];
+                let mut no_match = true;
+
+                for &s in right_remaining.iter() {
+                    let mut results = matches(s, rules, r, budget)?;
+                    if !results.is_empty() {
+                        no_match = false;
+                    }
+                    next.append(&mut results);
+                }
+                if no_match {
+                    right_remaining = vec![];
+                    break;
+                }
+
+                right_remaining = next;
+            }
+
+            left_remaining.append(&mut right_remaining);
+            left_remaining
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::vec_of_strings;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec_of_strings![
+            "0: 4 1 5",
+            "1: 2 3 | 3 2",
+            "2: 4 4 | 5 5",
+            "3: 4 5 | 5 4",
+            "4: \"a\"",
+            "5: \"b\"",
+            "",
+            "ababbb",
+            "bababa",
+            "abbbab",
+            "aaabbb",
+            "aaaabbb",
+        ];
+
+        assert_eq!(solve_part1(&lines, &Budget::unlimited()), Ok(Answer::from(2)));
+        assert_eq!(solve_part1(&lines, &Budget::new(None, Some(5))), Err(Exhausted::Steps(5)));
+        assert_eq!(solve_part1_regex(&lines), Answer::from(2));
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec_of_strings![
+            "42: 9 14 | 10 1",
+            "9: 14 27 | 1 26",
+            "10: 23 14 | 28 1",
+            "1: \"a\"",
+            "11: 42 31",
+            "5: 1 14 | 15 1",
+            "19: 14 1 | 14 14",
+            "12: 24 14 | 19 1",
+            "16: 15 1 | 14 14",
+            "31: 14 17 | 1 13",
+            "6: 14 14 | 1 14",
+            "2: 1 24 | 14 4",
+            "0: 8 11",
+            "13: 14 3 | 1 12",
+            "15: 1 | 14",
+            "17: 14 2 | 1 7",
+            "23: 25 1 | 22 14",
+            "28: 16 1",
+            "4: 1 1",
+            "20: 14 14 | 1 15",
+            "3: 5 14 | 16 1",
+            "27: 1 6 | 14 18",
+            "14: \"b\"",
+            "21: 14 1 | 1 14",
+            "25: 1 1 | 1 14",
+            "22: 14 14",
+            "8: 42",
+            "26: 14 22 | 1 20",
+            "18: 15 15",
+            "7: 14 5 | 1 21",
+            "24: 14 1",
+            "",
+            "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa",
+            "bbabbbbaabaabba",
+            "babbbbaabbbbbabbbbbbaabaaabaaa",
+            "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
+            "bbbbbbbaaaabbbbaaabbabaaa",
+            "bbbababbbbaaaaaaaabbababaaababaabab",
+            "ababaaaaaabaaab",
+            "ababaaaaabbbaba",
+            "baabbaaaabbaaaababbaababb",
+            "abbbbabbbbaaaababbbbbbaaaababb",
+            "aaaaabbaabaaaaababaa",
+            "aaaabbaaaabbaaa",
+            "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
+            "babaaabbbaaabaababbaabababaaab",
+            "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+        ];
+
+        assert_eq!(solve_part2(&lines, &Budget::unlimited()), Ok(Answer::from(12)));
+    }
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        let rules = || prop::collection::vec(any::<u64>(), 1..4);
+
+        prop_oneof![
+            "[a-z]".prop_map(|c| Rule::Char(c.chars().next().unwrap())),
+            rules().prop_map(Rule::Rules),
+            (rules(), rules()).prop_map(Rule::Or),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_rule_round_trip(n in any::<u64>(), r in rule()) {
+            let rules = build_rules(&vec![format!("{}: {}", n, r)]);
+
+            prop_assert_eq!(rules, vec![(n, r)].into_iter().collect::<HashMap<_, _>>());
+        }
+    }
+}